@@ -1,21 +1,19 @@
 use super::task::{CommandLookup, Task};
 use super::{
     action::Action,
-    components::{help_popup::HelpPopup, tasks_list::TasksList, Component},
+    components::{help_popup::HelpPopup, run_history_popup::RunHistoryPopup, tasks_list::TasksList, Component},
     tui,
 };
-use crate::native::tui::components::terminal_pane::{TerminalPane, TerminalPaneData, TerminalPaneState};
 use crate::native::tui::tui::Tui;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEventKind};
 use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction};
-use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Alignment, Rect};
 use ratatui::style::Modifier;
 use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::Paragraph;
 use std::io;
-use ratatui::Frame;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::debug;
@@ -29,9 +27,55 @@ pub struct App {
     focus: Focus,
     previous_focus: Focus,
     done_callback: Option<ThreadsafeFunction<(), ErrorStrategy::Fatal>>,
-    terminal_pane_data: [TerminalPaneData; 2],
-    pane_tasks: [Option<String>; 2], // Tasks assigned to panes 1 and 2 (0-indexed)
-    spacebar_mode: bool,
+    // Queued during the session (e.g. by pressing 'E' on a failed task), then run by the quit
+    // path once the terminal has been restored, so the program isn't drawn underneath the TUI.
+    launch_at_end: Option<Launchable>,
+}
+
+/// Selects how PTY output's SGR color sequences are rendered - `Auto` detects the terminal's
+/// own advertised support, while the others pin a specific fidelity (e.g. for CI logs captured
+/// on a limited terminal that would otherwise misreport truecolor support). Owned by
+/// `TasksList`, which is the only component that ever renders a pane - cycled via the 'C'
+/// keybinding through `TasksList::cycle_color_mode`, which re-applies it to every open pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    Auto,
+    Truecolor,
+    Ansi256,
+    Ansi16,
+}
+
+impl ColorMode {
+    pub(crate) fn next(self) -> Self {
+        match self {
+            ColorMode::Auto => ColorMode::Truecolor,
+            ColorMode::Truecolor => ColorMode::Ansi256,
+            ColorMode::Ansi256 => ColorMode::Ansi16,
+            ColorMode::Ansi16 => ColorMode::Auto,
+        }
+    }
+}
+
+/// An external program to run after the TUI has torn down and restored the terminal - the
+/// common "a task failed -> jump straight into an editor/shell to fix it" loop.
+#[derive(Debug, Clone)]
+pub struct Launchable {
+    pub program: String,
+    pub args: Vec<String>,
+    pub cwd: Option<String>,
+}
+
+impl Launchable {
+    /// Opens `task_name`'s captured output log in `$EDITOR` (falling back to `vi` if unset).
+    fn editor_for_task(task_name: &str) -> Self {
+        let program = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+        let log_path = TasksList::task_log_file_path(task_name);
+        Self {
+            program,
+            args: vec![log_path.to_string_lossy().into_owned()],
+            cwd: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -39,6 +83,8 @@ pub enum Focus {
     TaskList,
     TerminalPane(usize),
     HelpPopup,
+    Search,
+    RunHistory,
 }
 
 impl App {
@@ -49,10 +95,20 @@ impl App {
         target_names: Vec<String>,
         command_lookup: CommandLookup,
     ) -> Result<Self> {
-        let tasks_list = TasksList::new(tasks, target_names, command_lookup);
+        let mut tasks_list = TasksList::new(tasks, target_names, command_lookup);
         let help_popup = HelpPopup::new();
+        let run_history_popup = RunHistoryPopup::new();
+
+        // Restore a checked-in "dev server in pane 1, watcher in pane 2"-style layout, or
+        // whatever pane assignments were last auto-persisted, instead of requiring the user to
+        // pin each task by hand this session.
+        tasks_list.restore_pane_layout();
 
-        let components: Vec<Box<dyn Component>> = vec![Box::new(tasks_list), Box::new(help_popup)];
+        let components: Vec<Box<dyn Component>> = vec![
+            Box::new(tasks_list),
+            Box::new(help_popup),
+            Box::new(run_history_popup),
+        ];
 
         Ok(Self {
             tick_rate,
@@ -63,12 +119,25 @@ impl App {
             last_tick_key_events: Vec::new(),
             focus: Focus::TaskList,
             previous_focus: Focus::TaskList,
-            terminal_pane_data: [TerminalPaneData::new(), TerminalPaneData::new()],
-            pane_tasks: [None, None],
-            spacebar_mode: false,
+            launch_at_end: None,
         })
     }
 
+    /// Sets (or clears, via `None`) the height of the inline viewport, for CI-adjacent runs
+    /// that want the task UI anchored at the bottom of the terminal rather than taking over
+    /// the full alternate screen. Entering/leaving the alternate screen itself is the caller's
+    /// responsibility (see the `Tui` wrapper) - this only budgets the height this component
+    /// renders into and sizes PTYs against.
+    pub fn set_inline_viewport_height(&mut self, height: Option<u16>) {
+        if let Some(tasks_list) = self
+            .components
+            .iter_mut()
+            .find_map(|c| c.as_any_mut().downcast_mut::<TasksList>())
+        {
+            tasks_list.set_inline_viewport_height(height);
+        }
+    }
+
     // Only needed for the prototype testing mode via main.rs
     // TODO: Remove this after Nx integration
     pub fn queue_all_tasks(&mut self) {
@@ -102,25 +171,143 @@ impl App {
                 }
 
                 if let Focus::TerminalPane(pane_idx) = self.focus {
-                    if !self.is_interactive_mode() {
+                    let tasks_list = self.get_tasks_list_mut()?;
+
+                    // While typing a regex query for this pane's scrollback, the input owns the
+                    // keyboard until confirmed (Enter) or cancelled (Esc) - mirrors the task
+                    // list's own Focus::Search input handling below.
+                    if tasks_list.terminal_pane_data(pane_idx).is_searching() {
+                        match key.code {
+                            KeyCode::Esc => {
+                                tasks_list.terminal_pane_data_mut(pane_idx).cancel_search();
+                            }
+                            KeyCode::Enter => {
+                                tasks_list.terminal_pane_data_mut(pane_idx).confirm_search();
+                            }
+                            KeyCode::Backspace => {
+                                tasks_list.terminal_pane_data_mut(pane_idx).search_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                tasks_list.terminal_pane_data_mut(pane_idx).search_push_char(c);
+                            }
+                            _ => {}
+                        }
+                        return Ok(false);
+                    }
+
+                    // A mouse selection is active in this pane - copy it to the clipboard
+                    // instead of forwarding 'c'/'y' through to the PTY, even in interactive mode
+                    // where every other key reaches the running program untouched.
+                    if matches!(key.code, KeyCode::Char('c') | KeyCode::Char('y'))
+                        && tasks_list.terminal_pane_data(pane_idx).has_selection()
+                    {
+                        tasks_list
+                            .terminal_pane_data_mut(pane_idx)
+                            .copy_selection_to_clipboard()
+                            .ok();
+                        return Ok(false);
+                    }
+
+                    // Esc dismisses an active selection first, in both modes - only once there's
+                    // no selection left does it fall through to follow-mode/PTY handling below.
+                    if key.code == KeyCode::Esc && tasks_list.terminal_pane_data(pane_idx).has_selection() {
+                        tasks_list.terminal_pane_data_mut(pane_idx).clear_selection();
+                        return Ok(false);
+                    }
+
+                    if !tasks_list.is_interactive_mode() {
                         match key.code {
                             KeyCode::Tab => {
-                                self.focus_next();
+                                tasks_list.focus_next();
+                                self.focus = tasks_list.get_focus();
                             }
                             KeyCode::BackTab => {
-                                self.focus_previous();
+                                tasks_list.focus_previous();
+                                self.focus = tasks_list.get_focus();
                             }
                             KeyCode::Char('b') => {
-                                self.toggle_task_list();
+                                tasks_list.toggle_task_list();
+                            }
+                            KeyCode::Char('x') => {
+                                tasks_list.close_focused_pane();
+                                self.focus = tasks_list.get_focus();
+                            }
+                            // Cycles the focused pane's task forward/backward through the full
+                            // task list, wrapping around - swaps a pane's contents without
+                            // returning to the task list to pin a specific task by number.
+                            KeyCode::Char(']') => {
+                                tasks_list.cycle_pane_task(pane_idx, true);
+                            }
+                            KeyCode::Char('[') => {
+                                tasks_list.cycle_pane_task(pane_idx, false);
+                            }
+                            KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                tasks_list.shrink_first_pane();
+                            }
+                            KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                tasks_list.grow_first_pane();
+                            }
+                            KeyCode::Char('v')
+                                if key.modifiers.contains(KeyModifiers::CONTROL)
+                                    && tasks_list.terminal_pane_data(pane_idx).has_selection() =>
+                            {
+                                tasks_list.terminal_pane_data_mut(pane_idx).set_selection_block_mode();
+                            }
+                            KeyCode::Char('v') if tasks_list.terminal_pane_data(pane_idx).has_selection() => {
+                                tasks_list.terminal_pane_data_mut(pane_idx).set_selection_character_mode();
+                            }
+                            KeyCode::Char('V') if tasks_list.terminal_pane_data(pane_idx).has_selection() => {
+                                tasks_list.terminal_pane_data_mut(pane_idx).set_selection_line_mode();
+                            }
+                            // Enters keyboard-driven copy mode at the top-left of the visible
+                            // viewport when nothing is selected yet - from here, cursor movement
+                            // to extend the selection (and Enter/'y' to confirm) is handled the
+                            // same way as mouse-drag selection, via handle_key_event below.
+                            KeyCode::Char('v') if !tasks_list.terminal_pane_data(pane_idx).has_selection() => {
+                                tasks_list.terminal_pane_data_mut(pane_idx).start_selection(0, 0);
+                            }
+                            // Case-insensitive/regex matching, centering the viewport on the
+                            // active hit, and the "k/m matches" indicator in the pane's border
+                            // title are rendered by TerminalPane/TerminalPaneData's own search
+                            // and draw implementation - this only owns triggering and leaving it.
+                            KeyCode::Char('/') => {
+                                tasks_list.terminal_pane_data_mut(pane_idx).enter_search_mode();
+                            }
+                            KeyCode::Char('n')
+                                if tasks_list.terminal_pane_data(pane_idx).has_active_search() =>
+                            {
+                                tasks_list.terminal_pane_data_mut(pane_idx).next_search_match();
+                            }
+                            KeyCode::Char('N')
+                                if tasks_list.terminal_pane_data(pane_idx).has_active_search() =>
+                            {
+                                tasks_list.terminal_pane_data_mut(pane_idx).previous_search_match();
+                            }
+                            // Leaves an active search (clearing its matches and the title's
+                            // "k/m matches" indicator) now that it's no longer claimed by the
+                            // is_searching()/has_selection() checks above - mirrors
+                            // TasksList's own `Esc` -> `cancel_search` binding.
+                            KeyCode::Esc
+                                if tasks_list.terminal_pane_data(pane_idx).has_active_search() =>
+                            {
+                                tasks_list.terminal_pane_data_mut(pane_idx).cancel_search();
                             }
                             _ => {
-                                let terminal_pane_data = &mut self.terminal_pane_data[pane_idx];
-                                // Forward other keys for interactivity, scrolling (j/k) etc
+                                // Forward other keys for interactivity, scrolling (j/k) etc. This
+                                // is also how vi-style scrollback motions (h/j/k/l, w/b, 0/$, g/G,
+                                // Ctrl-u/Ctrl-d), PageUp/PageDown/Home/End paging through the
+                                // ring-buffer scrollback, and the viewport lock/follow-mode
+                                // toggle on Esc reach the pane - TerminalPaneData::handle_key_event
+                                // owns that cursor/lock state, clamps the scroll offset against the
+                                // buffer's current length, and interprets the raw KeyEvent itself.
+                                // This now reaches the pane TasksList actually draws (pane_idx is
+                                // looked up on tasks_list, not on a separate copy App used to keep).
+                                let terminal_pane_data = tasks_list.terminal_pane_data_mut(pane_idx);
                                 terminal_pane_data.handle_key_event(key).ok();
                             }
                         }
                     } else {
-                        let terminal_pane_data = &mut self.terminal_pane_data[pane_idx];
+                        let terminal_pane_data = tasks_list.terminal_pane_data_mut(pane_idx);
                         // Forward all key events to the currently focused pane in interactive mode
                         terminal_pane_data.handle_key_event(key)?;
                     }
@@ -147,6 +334,63 @@ impl App {
                     return Ok(false);
                 }
 
+                // Only handle 'H' (run history) if we're not in interactive mode
+                if matches!(key.code, KeyCode::Char('H')) && !self.is_interactive_mode() {
+                    let show_run_history = !matches!(self.focus, Focus::RunHistory);
+                    if let Some(run_history_popup) = self
+                        .components
+                        .iter_mut()
+                        .find_map(|c| c.as_any_mut().downcast_mut::<RunHistoryPopup>())
+                    {
+                        run_history_popup.set_visible(show_run_history);
+                    }
+                    if show_run_history {
+                        self.previous_focus = self.focus;
+                        self.focus = Focus::RunHistory;
+                    } else {
+                        self.focus = self.previous_focus;
+                    }
+                    return Ok(false);
+                }
+
+                // If the run history popup is open, handle its keyboard events
+                if matches!(self.focus, Focus::RunHistory) {
+                    match key.code {
+                        KeyCode::Esc => {
+                            if let Some(run_history_popup) = self
+                                .components
+                                .iter_mut()
+                                .find_map(|c| c.as_any_mut().downcast_mut::<RunHistoryPopup>())
+                            {
+                                run_history_popup.set_visible(false);
+                            }
+                            self.focus = self.previous_focus;
+                        }
+                        KeyCode::Left | KeyCode::Char('h') => {
+                            if let Some(run_history_popup) = self
+                                .components
+                                .iter_mut()
+                                .find_map(|c| c.as_any_mut().downcast_mut::<RunHistoryPopup>())
+                            {
+                                run_history_popup.previous_run();
+                            }
+                            return Ok(false);
+                        }
+                        KeyCode::Right | KeyCode::Char('l') => {
+                            if let Some(run_history_popup) = self
+                                .components
+                                .iter_mut()
+                                .find_map(|c| c.as_any_mut().downcast_mut::<RunHistoryPopup>())
+                            {
+                                run_history_popup.next_run();
+                            }
+                            return Ok(false);
+                        }
+                        _ => {}
+                    }
+                    return Ok(false);
+                }
+
                 // If shortcuts popup is open, handle its keyboard events
                 if matches!(self.focus, Focus::HelpPopup) {
                     match key.code {
@@ -185,15 +429,57 @@ impl App {
                     return Ok(false);
                 }
 
+                // If the distinct fuzzy-search mode is active, it owns the keyboard until
+                // confirmed (Enter) or cancelled (Esc) - separate from task-list filtering
+                if matches!(self.focus, Focus::Search) {
+                    let tasks_list = self.get_tasks_list_mut()?;
+                    match key.code {
+                        KeyCode::Esc => {
+                            tasks_list.cancel_search();
+                            self.focus = Focus::TaskList;
+                        }
+                        KeyCode::Enter => {
+                            // Leave input mode but keep the matches live so n/N keep working
+                            // once focus is back on the task list
+                            self.focus = Focus::TaskList;
+                        }
+                        KeyCode::Char(c) => {
+                            tasks_list.add_search_char(c);
+                        }
+                        KeyCode::Backspace => {
+                            tasks_list.remove_search_char();
+                        }
+                        _ => {}
+                    }
+                    return Ok(false);
+                }
+
                 // Handle spacebar toggle regardless of focus
                 if key.code == KeyCode::Char(' ') {
-                    self.toggle_output_visibility();
+                    let tasks_list = self.get_tasks_list_mut()?;
+                    tasks_list.toggle_output_visibility();
                     return Ok(false); // Skip other key handling
                 }
 
+                // Ctrl-Left/Right resizes the task-list/output divider from the task list - the
+                // equivalent binding for the divider between two output panes is handled above,
+                // in the Focus::TerminalPane block.
+                if matches!(self.focus, Focus::TaskList)
+                    && key.modifiers.contains(KeyModifiers::CONTROL)
+                    && matches!(key.code, KeyCode::Left | KeyCode::Right)
+                {
+                    let tasks_list = self.get_tasks_list_mut()?;
+                    if key.code == KeyCode::Left {
+                        tasks_list.shrink_task_list();
+                    } else {
+                        tasks_list.grow_task_list();
+                    }
+                    return Ok(false);
+                }
+
                 if matches!(self.focus, Focus::TaskList) {
 
-                    let mut tasks_list = self.get_tasks_list_mut();
+                    let mut tasks_list = self.get_tasks_list_mut()?;
                     match key.code {
                         KeyCode::Down | KeyCode::Char('j') => {
                             tasks_list.next();
@@ -202,13 +488,41 @@ impl App {
                             tasks_list.previous();
                         }
                         KeyCode::Left => {
-                            tasks_list.previous_page();
+                            if tasks_list.selected_is_group_header() {
+                                tasks_list.toggle_selected_group();
+                            } else {
+                                tasks_list.previous_page();
+                            }
                         }
                         KeyCode::Right => {
-                            tasks_list.next_page();
+                            if tasks_list.selected_is_group_header() {
+                                tasks_list.toggle_selected_group();
+                            } else {
+                                tasks_list.next_page();
+                            }
+                        }
+                        KeyCode::Enter if !tasks_list.filter_mode && tasks_list.selected_is_group_header() => {
+                            tasks_list.toggle_selected_group();
+                        }
+                        KeyCode::Tab if !tasks_list.filter_mode => {
+                            tasks_list.next_tab();
+                        }
+                        KeyCode::BackTab if !tasks_list.filter_mode => {
+                            tasks_list.previous_tab();
                         }
                         KeyCode::Esc => {
-                            tasks_list.clear_filter();
+                            if tasks_list.has_active_search() {
+                                tasks_list.cancel_search();
+                            } else if tasks_list.filter_mode {
+                                // Cancel the in-progress search and restore prior selection
+                                tasks_list.cancel_filter();
+                            } else {
+                                // Already persisted (or no filter at all) - clear it entirely
+                                tasks_list.clear_filter();
+                            }
+                        }
+                        KeyCode::Enter if tasks_list.filter_mode => {
+                            tasks_list.confirm_filter();
                         }
                         KeyCode::Char(c) if tasks_list.filter_mode => {
                             tasks_list.add_filter_char(c);
@@ -234,45 +548,101 @@ impl App {
                         KeyCode::Char('b') => {
                             self.toggle_task_list();
                         }
+                        KeyCode::Char('g') if !tasks_list.filter_mode => {
+                            tasks_list.toggle_grouped_view();
+                        }
+                        KeyCode::Char('f') if !tasks_list.filter_mode => {
+                            tasks_list.enter_search_mode();
+                            self.focus = Focus::Search;
+                        }
+                        KeyCode::Char('n') if tasks_list.has_active_search() => {
+                            tasks_list.next_match();
+                        }
+                        KeyCode::Char('N') if tasks_list.has_active_search() => {
+                            tasks_list.previous_match();
+                        }
                         KeyCode::Char('q') => {
                             self.should_quit = true;
                         }
                         KeyCode::Char('0') => {
-                            self.clear_all_panes();
+                            tasks_list.clear_all_panes();
+                            self.focus = tasks_list.get_focus();
                         }
-                        KeyCode::Char('1') => {
-                            self.assign_current_task_to_pane(0);
+                        // '1'..'9' pin the selected task into the corresponding pane, up to
+                        // however many panes are configured (see `add_pane`/`remove_pane`).
+                        KeyCode::Char(c @ '1'..='9') => {
+                            let pane_idx = c.to_digit(10).unwrap() as usize - 1;
+                            tasks_list.assign_current_task_to_pane(pane_idx);
+                            self.focus = tasks_list.get_focus();
                         }
-                        KeyCode::Char('2') => {
-                            self.assign_current_task_to_pane(1);
+                        KeyCode::Char('P') => {
+                            tasks_list.reload_layout_preset();
                         }
-                        _ => {}
-                    }
-
-                    if self.spacebar_mode {
-                        let tasks_list = self.get_tasks_list();
-                        if let Some(task_name) = tasks_list.get_selected_task_name() {
-                            self.pane_tasks[0] = Some(task_name.clone());
+                        KeyCode::Char('+') => {
+                            tasks_list.pin_selected_task_to_next_free_pane();
+                        }
+                        // Cycles every pane's PTY color fidelity (auto/truecolor/256/16) - for
+                        // terminals (e.g. some CI log viewers) that misreport their own
+                        // truecolor support and need output downsampled to render readably.
+                        KeyCode::Char('C') => {
+                            tasks_list.cycle_color_mode();
+                        }
+                        // Quits and drops straight into $EDITOR on the selected task's
+                        // captured output - most useful on a task that just failed.
+                        KeyCode::Char('E') => {
+                            if let Some(task_name) = tasks_list.get_selected_task_name() {
+                                self.launch_at_end = Some(Launchable::editor_for_task(&task_name));
+                                self.should_quit = true;
+                            }
                         }
+                        _ => {}
                     }
                 }
             }
             tui::Event::Mouse(mouse_event) => match self.focus {
-                Focus::TerminalPane(pane_idx) => match mouse_event.kind {
-                    MouseEventKind::ScrollUp => {
-                        self.terminal_pane_data[pane_idx]
-                            .handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::empty()))
-                            .ok();
-                    }
-                    MouseEventKind::ScrollDown => {
-                        self.terminal_pane_data[pane_idx]
-                            .handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()))
-                            .ok();
+                Focus::TerminalPane(pane_idx) => {
+                    let tasks_list = self.get_tasks_list_mut()?;
+                    // Same ring-buffer scrollback that PageUp/PageDown/Home/End page through via
+                    // TerminalPaneData::handle_key_event (see the Focus::TerminalPane key match
+                    // above) - the wheel just synthesizes an Up/Down KeyEvent against the same
+                    // pane TasksList actually draws, rather than a step of its own.
+                    match mouse_event.kind {
+                        MouseEventKind::ScrollUp => {
+                            tasks_list.terminal_pane_data_mut(pane_idx).clear_selection();
+                            tasks_list
+                                .terminal_pane_data_mut(pane_idx)
+                                .handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::empty()))
+                                .ok();
+                        }
+                        MouseEventKind::ScrollDown => {
+                            tasks_list.terminal_pane_data_mut(pane_idx).clear_selection();
+                            tasks_list
+                                .terminal_pane_data_mut(pane_idx)
+                                .handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()))
+                                .ok();
+                        }
+                        MouseEventKind::Down(_) => {
+                            // Start a fresh selection anchored at this cell, translated to an
+                            // absolute buffer coordinate using the pane's current scrollback offset
+                            tasks_list
+                                .terminal_pane_data_mut(pane_idx)
+                                .start_selection(mouse_event.column, mouse_event.row);
+                        }
+                        MouseEventKind::Drag(_) => {
+                            tasks_list
+                                .terminal_pane_data_mut(pane_idx)
+                                .update_selection(mouse_event.column, mouse_event.row);
+                        }
+                        MouseEventKind::Up(_) => {
+                            tasks_list
+                                .terminal_pane_data_mut(pane_idx)
+                                .update_selection(mouse_event.column, mouse_event.row);
+                        }
+                        _ => {}
                     }
-                    _ => {}
-                },
+                }
                 Focus::TaskList => {
-                    let tasks_list = self.get_tasks_list_mut();
+                    let tasks_list = self.get_tasks_list_mut()?;
 
                     match mouse_event.kind {
                         MouseEventKind::ScrollUp => {
@@ -399,13 +769,26 @@ impl App {
                 }
             }).ok();
             }
+            // Plays back a pre-built sequence of high-level actions in order, by re-enqueueing
+            // them one at a time onto the same channel `handle_action` is driven from. This is
+            // how a test harness or a `--pin`-style CLI flag drives the focus/pin/filter state
+            // machine deterministically, without synthesizing raw `KeyEvent`s.
+            Action::Sequence(actions) => {
+                for queued_action in actions {
+                    action_tx.send(queued_action).ok();
+                }
+            }
             _ => {}
         }
 
         // Update components
         for component in self.components.iter_mut() {
-            if let Ok(Some(new_action)) = component.update(action.clone()) {
-                action_tx.send(new_action).ok();
+            match component.update(action.clone()) {
+                Ok(Some(new_action)) => {
+                    action_tx.send(new_action).ok();
+                }
+                Ok(None) => {}
+                Err(e) => Self::log_recoverable_error("component update", e),
             }
         }
     }
@@ -426,321 +809,62 @@ impl App {
         }
     }
 
-    pub fn is_interactive_mode(&self) -> bool {
-        match self.focus {
-            Focus::TerminalPane(pane_idx) => self.terminal_pane_data[pane_idx].is_interactive(),
-            _ => false,
-        }
-    }
-
-    pub fn focus(&self) -> Focus {
-        self.focus
-    }
-
-    pub fn focus_next(&mut self) {
-        let num_panes = self.pane_tasks.iter().filter(|t| t.is_some()).count();
-        if num_panes == 0 {
-            return; // No panes to focus
-        }
-
-        self.focus = match self.focus {
-            Focus::TaskList => {
-                // Move to first visible pane
-                if let Some(first_pane) = self.pane_tasks.iter().position(|t| t.is_some()) {
-                    Focus::TerminalPane(first_pane)
-                } else {
-                    Focus::TaskList
-                }
-            }
-            Focus::TerminalPane(current_pane) => {
-                // Find next visible pane or go back to task list
-                let next_pane = (current_pane + 1..2).find(|&idx| self.pane_tasks[idx].is_some());
-
-                match next_pane {
-                    Some(pane) => Focus::TerminalPane(pane),
-                    None => Focus::TaskList,
-                }
-            }
-            Focus::HelpPopup => Focus::TaskList,
+    /// Runs a queued `launch_at_end`, if any, forwarding its exit status to the caller. Must
+    /// only be called once the terminal has been restored (raw mode off, alternate screen
+    /// left) - `Launchable::program` inherits this process's stdio, so running it earlier
+    /// would draw it underneath the still-active TUI.
+    pub fn run_launch_at_end(&self) -> Result<Option<std::process::ExitStatus>> {
+        let Some(launchable) = &self.launch_at_end else {
+            return Ok(None);
         };
-    }
 
-    pub fn focus_previous(&mut self) {
-        let num_panes = self.pane_tasks.iter().filter(|t| t.is_some()).count();
-        if num_panes == 0 {
-            return; // No panes to focus
+        let mut command = std::process::Command::new(&launchable.program);
+        command.args(&launchable.args);
+        if let Some(cwd) = &launchable.cwd {
+            command.current_dir(cwd);
         }
 
-        self.focus = match self.focus {
-            Focus::TaskList => {
-                // Move to last visible pane
-                if let Some(last_pane) = (0..2).rev().find(|&idx| self.pane_tasks[idx].is_some()) {
-                    Focus::TerminalPane(last_pane)
-                } else {
-                    Focus::TaskList
-                }
-            }
-            Focus::TerminalPane(current_pane) => {
-                // Find previous visible pane or go back to task list
-                if current_pane > 0 {
-                    if let Some(prev_pane) = (0..current_pane)
-                        .rev()
-                        .find(|&idx| self.pane_tasks[idx].is_some())
-                    {
-                        Focus::TerminalPane(prev_pane)
-                    } else {
-                        Focus::TaskList
-                    }
-                } else {
-                    Focus::TaskList
-                }
-            }
-            Focus::HelpPopup => Focus::TaskList,
-        };
-    }
-
-    /// Checks if the current view has any visible output panes.
-    pub fn has_visible_panes(&self) -> bool {
-        self.pane_tasks.iter().any(|t| t.is_some())
-    }
-
-    /// Moves the selection to the next task in the list.
-    /// If in spacebar mode, updates the output pane to show the newly selected task.
-    fn next_task(&mut self, tasks_list: &mut TasksList) {
-        tasks_list.next();
-
-        // Only update pane 1 if we're in spacebar mode
-        if self.spacebar_mode {
-            if let Some(task_name) = tasks_list.get_selected_task_name() {
-                self.pane_tasks[0] = Some(task_name.clone());
-            }
-        }
-        tasks_list.reset_scroll();
-    }
-
-    fn assign_current_task_to_pane(&mut self, pane_idx: usize) {
-        let tasks_list = self.get_tasks_list();
-        if let Some(task_name) = tasks_list.get_selected_task_name() {
-            // If we're in spacebar mode and this is pane 0, convert to pinned mode
-            if self.spacebar_mode && pane_idx == 0 {
-                self.spacebar_mode = false;
-                self.focus = Focus::TerminalPane(pane_idx);
-                return;
-            }
-
-            // Check if the task is already pinned to the pane
-            if self.pane_tasks[pane_idx].as_deref() == Some(task_name.as_str()) {
-                // Unpin the task if it's already pinned
-                self.pane_tasks[pane_idx] = None;
-
-                // Adjust focused pane if necessary
-                if !self.has_visible_panes() {
-                    self.focus = Focus::TaskList;
-                    self.spacebar_mode = false;
-                }
-                return;
-            }
-
-            // Pin the task to the specified pane
-            self.pane_tasks[pane_idx] = Some(task_name.clone());
-            self.focus = Focus::TaskList;
-            self.spacebar_mode = false; // Exit spacebar mode when pinning
-        }
+        command
+            .status()
+            .map(Some)
+            .map_err(|e| eyre!("failed to launch '{}': {e}", launchable.program))
     }
 
-    fn clear_all_panes(&mut self) {
-        self.pane_tasks = [None, None];
-        self.spacebar_mode = false;
-        self.focus = Focus::TaskList;
-    }
-
-    /// Toggles the visibility of the output pane for the currently selected task.
-    /// In spacebar mode, the output follows the task selection.
-    pub fn toggle_output_visibility(&mut self) {
-        let has_visible_panes = self.has_visible_panes();
-        let tasks_list = self.get_tasks_list_mut();
-        // Ensure task list is visible after every spacebar interaction
-        tasks_list.hide();
-
-        if let Some(task_name) = tasks_list.get_selected_task_name() {
-            if has_visible_panes {
-                // Always clear all panes when toggling with spacebar
-                self.clear_all_panes();
-                self.spacebar_mode = false;
-            } else {
-                // Show current task in pane 1 in spacebar mode
-                self.pane_tasks = [Some(task_name.clone()), None];
-                self.spacebar_mode = true; // Enter spacebar mode
-            }
-        }
+    /// Delegates to the `TasksList` component, which owns the only pane state that's ever
+    /// actually rendered - a missing component falls back to "not interactive" rather than
+    /// panicking.
+    pub fn is_interactive_mode(&self) -> bool {
+        self.get_tasks_list()
+            .map(|tasks_list| tasks_list.is_interactive_mode())
+            .unwrap_or(false)
     }
 
-    /// Toggles the visibility of the task list panel
-    fn toggle_task_list(&mut self) {
-        // Only allow hiding if at least one pane is visible
-        if self.has_visible_panes() {
-            let tasks_list = self.get_tasks_list_mut();
-            tasks_list.toggle();
-        }
+    pub fn focus(&self) -> Focus {
+        self.focus
     }
 
-    fn get_tasks_list(&self) -> &TasksList {
+    /// Looks up the `TasksList` component, returning a recoverable error instead of panicking
+    /// if it's ever missing - a missing component is a programming error, but one that should
+    /// surface as a logged, drawn-as-a-status-line failure rather than aborting the TUI thread
+    /// and leaving the terminal in raw mode.
+    fn get_tasks_list(&self) -> Result<&TasksList> {
         self.components
             .iter()
             .find_map(|c| c.as_any().downcast_ref::<TasksList>())
-            .expect("TasksList component does not exist")
+            .ok_or_else(|| eyre!("TasksList component does not exist"))
     }
 
-    fn get_tasks_list_mut(&mut self) -> &mut TasksList {
+    fn get_tasks_list_mut(&mut self) -> Result<&mut TasksList> {
         self.components
             .iter_mut()
             .find_map(|c| c.as_any_mut().downcast_mut::<TasksList>())
-            .expect("TasksList component does not exist")
+            .ok_or_else(|| eyre!("TasksList component does not exist"))
     }
 
-    pub fn draw_terminal_panes(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
-
-        let num_active_panes = self.pane_tasks.iter().filter(|t| t.is_some()).count();
-
-        match num_active_panes {
-            0 => (), // No panes to render
-            1 => {
-                if self.pane_tasks[1].is_some() {
-                    let output_chunks = Layout::default()
-                        .direction(Direction::Horizontal)
-                        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                        .spacing(2)
-                        .split(output_area);
-
-                    // Render placeholder for pane 1
-                    let placeholder = Paragraph::new("Press 1 on a task to show it here")
-                        .block(
-                            Block::default()
-                                .title("  Output 1  ")
-                                .borders(Borders::ALL)
-                                .border_style(Style::default().fg(Color::DarkGray)),
-                        )
-                        .style(Style::default().fg(Color::DarkGray))
-                        .alignment(Alignment::Center);
-
-                    f.render_widget(placeholder, output_chunks[0]);
-
-                    // Get task data before rendering
-                    if let Some(task_name) = &self.pane_tasks[1] {
-                        if let Some(task) = self.tasks.iter_mut().find(|t| t.name == *task_name)
-                        {
-                            let mut terminal_pane_data = &mut self.terminal_pane_data[1];
-                            terminal_pane_data.status = task.status;
-                            terminal_pane_data.is_continuous = task.continuous;
-
-                            if let Some(pty) = &mut task.pty {
-                                terminal_pane_data.pty = Some(pty.clone());
-                            }
-
-                            let is_focused = match self.focus {
-                                Focus::TerminalPane(focused_pane_idx) => {
-                                    1 == focused_pane_idx
-                                }
-                                _ => false,
-                            };
-                            let mut state = TerminalPaneState::default();
-
-                            let terminal_pane = TerminalPane::new()
-                                .task_name(task.name.clone())
-                                .pty_data(&mut terminal_pane_data)
-                                .focused(is_focused)
-                                .continuous(task.continuous);
-
-                            f.render_stateful_widget(
-                                terminal_pane,
-                                output_chunks[1],
-                                &mut state,
-                            );
-                        }
-                    }
-                } else if let Some((pane_idx, Some(task_name))) = self
-                    .pane_tasks
-                    .iter()
-                    .enumerate()
-                    .find(|(_, t)| t.is_some())
-                {
-                    if let Some(task) = self.tasks.iter_mut().find(|t| t.name == *task_name) {
-                        let mut terminal_pane_data = &mut self.terminal_pane_data[pane_idx];
-                        terminal_pane_data.status = task.status;
-                        terminal_pane_data.is_continuous = task.continuous;
-
-                        if let Some(pty) = &mut task.pty {
-                            terminal_pane_data.pty = Some(pty.clone());
-                        }
-
-                        let is_focused = match self.focus {
-                            Focus::TerminalPane(focused_pane_idx) => 0 == focused_pane_idx,
-                            _ => false,
-                        };
-                        let mut state = TerminalPaneState::default();
-
-                        let terminal_pane = TerminalPane::new()
-                            .task_name(task.name.clone())
-                            .pty_data(&mut terminal_pane_data)
-                            .focused(is_focused)
-                            .continuous(task.continuous);
-
-                        f.render_stateful_widget(terminal_pane, output_area, &mut state);
-                    }
-                }
-            }
-            _ => {
-                let output_chunks = Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .spacing(2)
-                    .split(output_area);
-
-                for (pane_idx, chunk) in output_chunks.iter().enumerate() {
-                    if let Some(task_name) = &self.pane_tasks[pane_idx] {
-                        if let Some(task) = self.tasks.iter_mut().find(|t| t.name == *task_name)
-                        {
-                            let mut terminal_pane_data = &mut self.terminal_pane_data[pane_idx];
-                            terminal_pane_data.status = task.status;
-                            terminal_pane_data.is_continuous = task.continuous;
-
-                            if let Some(pty) = &mut task.pty {
-                                terminal_pane_data.pty = Some(pty.clone());
-                            }
-
-                            let is_focused = match self.focus {
-                                Focus::TerminalPane(focused_pane_idx) => {
-                                    pane_idx == focused_pane_idx
-                                }
-                                _ => false,
-                            };
-                            let mut state = TerminalPaneState::default();
-
-                            let terminal_pane = TerminalPane::new()
-                                .task_name(task.name.clone())
-                                .pty_data(&mut terminal_pane_data)
-                                .focused(is_focused)
-                                .continuous(task.continuous);
-
-                            f.render_stateful_widget(terminal_pane, *chunk, &mut state);
-                        }
-                    } else {
-                        let placeholder =
-                            Paragraph::new("Press 1 or 2 on a task to show it here")
-                                .block(
-                                    Block::default()
-                                        .title(format!("Output {}", pane_idx + 1))
-                                        .borders(Borders::ALL)
-                                        .border_style(Style::default().fg(Color::DarkGray)),
-                                )
-                                .style(Style::default().fg(Color::DarkGray))
-                                .alignment(Alignment::Center);
-
-                        f.render_widget(placeholder, *chunk);
-                    }
-                }
-            }
-        }
-        Ok(())
+    /// Logs a component's `update` failure from `handle_action` instead of dropping it -
+    /// `handle_action` itself doesn't return a `Result`.
+    fn log_recoverable_error(context: &str, error: color_eyre::eyre::Report) {
+        tracing::error!("{context}: {error:?}");
     }
+
 }
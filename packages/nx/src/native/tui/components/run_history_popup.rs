@@ -0,0 +1,146 @@
+use crate::native::tui::{
+    action::Action,
+    components::Component,
+    run_history::{RunHistoryEntry, RunHistoryStore},
+};
+use color_eyre::eyre::Result;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+use std::any::Any;
+
+/// Shows previously-persisted run history (see `run_history::RunHistoryStore`) so users can
+/// compare task durations and outcomes across invocations - reached the same way as the help
+/// popup, via a key binding that toggles `Focus::RunHistory`.
+pub struct RunHistoryPopup {
+    visible: bool,
+    runs: Vec<RunHistoryEntry>,
+    selected_run: usize,
+}
+
+impl RunHistoryPopup {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            runs: Vec::new(),
+            selected_run: 0,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows or hides the popup, reloading persisted runs from disk each time it's opened so
+    /// it reflects the latest history even if it was last opened in an earlier run.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+        if visible {
+            self.runs = RunHistoryStore::load_all();
+            self.selected_run = 0;
+        }
+    }
+
+    /// Selects the next most recent run.
+    pub fn next_run(&mut self) {
+        if !self.runs.is_empty() {
+            self.selected_run = (self.selected_run + 1) % self.runs.len();
+        }
+    }
+
+    /// Selects the next least recent run.
+    pub fn previous_run(&mut self) {
+        if !self.runs.is_empty() {
+            self.selected_run = (self.selected_run + self.runs.len() - 1) % self.runs.len();
+        }
+    }
+}
+
+impl Default for RunHistoryPopup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for RunHistoryPopup {
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+
+        let popup_width = area.width.saturating_sub(area.width / 4).max(40);
+        let popup_height = area.height.saturating_sub(area.height / 4).max(10);
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+            y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let Some(run) = self.runs.get(self.selected_run) else {
+            let block = Block::default()
+                .title("Run History")
+                .borders(Borders::ALL);
+            f.render_widget(
+                Paragraph::new("No persisted run history yet.").block(block),
+                popup_area,
+            );
+            return Ok(());
+        };
+
+        let header = Row::new(vec![
+            Cell::from("Task"),
+            Cell::from("Status"),
+            Cell::from("Cache"),
+            Cell::from("Duration"),
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows = run.tasks.iter().map(|task| {
+            Row::new(vec![
+                Cell::from(task.task_id.clone()),
+                Cell::from(task.status.clone()),
+                Cell::from(task.cache.clone()),
+                Cell::from(format!("{}ms", task.duration_ms)),
+            ])
+        });
+
+        let constraints = [
+            Constraint::Fill(1),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(12),
+        ];
+
+        let title = format!(
+            "Run History ({}/{}) - \u{2190}/\u{2192} to switch runs, Esc to close",
+            self.selected_run + 1,
+            self.runs.len()
+        );
+
+        let table = Table::new(rows, constraints)
+            .header(header)
+            .block(Block::default().title(title).borders(Borders::ALL));
+
+        f.render_widget(table, popup_area);
+
+        Ok(())
+    }
+
+    fn update(&mut self, _action: Action) -> Result<Option<Action>> {
+        Ok(None)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
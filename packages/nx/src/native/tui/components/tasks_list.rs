@@ -1,22 +1,28 @@
 use crate::native::tui::{
     action::Action,
-    app::Focus,
+    app::{ColorMode, Focus},
     components::Component,
+    layout_presets::LayoutPreset,
+    pane_session::PaneSessionState,
     pty::PtyInstance,
+    run_history::{RunHistoryStore, TaskHistoryRecord},
     task::{CommandLookup, Task, TaskResult},
     utils,
 };
 use color_eyre::eyre::Result;
 use crossterm::event::KeyEvent;
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Padding, Paragraph, Row, ScrollbarState, Table},
+    widgets::{Block, Borders, Cell, Gauge, Padding, Paragraph, Row, ScrollbarState, Sparkline, Table},
     Frame,
 };
 use std::any::Any;
-use std::io;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use super::pagination::Pagination;
@@ -28,6 +34,16 @@ const CACHE_STATUS_LOCAL_KEPT_EXISTING: &str = "Kept Existing";
 const CACHE_STATUS_LOCAL: &str = "Local";
 const CACHE_STATUS_REMOTE: &str = "Remote";
 const CACHE_STATUS_MISS: &str = "Miss";
+// Where persisted task output is stored, so it can be reloaded the next time the TUI opens
+// against the same workspace, instead of only living in memory for the current run.
+const TUI_LOG_DIR: &str = "tmp/nx-tui-logs";
+// Number of output panes a session starts with - grown or shrunk at runtime via
+// `add_pane`/`remove_pane`.
+const DEFAULT_PANE_COUNT: usize = 2;
+// Marks a group-header entry in the selection manager's entry list - a sentinel byte that
+// can never appear in a real task id, so header rows can share the same `Vec<Option<String>>`
+// as task rows without a parallel enum.
+const GROUP_HEADER_PREFIX: &str = "\u{0}";
 
 /// A list component that displays and manages tasks in a terminal UI.
 /// Provides filtering, sorting, and output display capabilities.
@@ -44,16 +60,138 @@ pub struct TasksList {
     scroll_offset: usize,
     scrollbar_state: ScrollbarState,
     content_height: usize,
-    pane_tasks: [Option<String>; 2], // Tasks assigned to panes 1 and 2 (0-indexed)
+    pane_tasks: Vec<Option<String>>, // Tasks assigned to each pane, 0-indexed, growable at runtime
     focused_pane: Option<usize>,     // Currently focused pane (if any)
     last_task_start: Option<u128>,   // Timestamp of last task start
     queued_tasks: Vec<usize>,        // Indices of tasks queued to start
     is_dimmed: bool,
     spacebar_mode: bool, // Whether we're in spacebar mode (output follows selection)
-    terminal_pane_data: [TerminalPaneData; 2],
+    terminal_pane_data: Vec<TerminalPaneData>,
+    // PTY-output color fidelity applied to every pane, current and future - cycled via 'C'.
+    color_mode: ColorMode,
     command_lookup: CommandLookup,
     target_names: Vec<String>,
     task_list_hidden: bool, // New field to track if task list is hidden
+    // The task that was selected when search mode was entered, so that cancelling restores it.
+    pre_filter_selection: Option<String>,
+    // Matched character indices (into each task name's chars) from the current filter's fuzzy
+    // match, so the renderer can bold/underline them - absent entries (no filter, or a task
+    // that's merely tab-filtered rather than fuzzy-matched) just render with no emphasis.
+    filter_match_indices: std::collections::HashMap<String, Vec<usize>>,
+    // Index into `target_names` for the active tab, or `None` for the "All" tab.
+    active_tab: Option<usize>,
+    // Distinct from the filter above: search keeps every task visible and just
+    // ranks/highlights matches, letting the user cycle through hits with n/N.
+    pub search_mode: bool,
+    search_query: String,
+    search_results: SearchResults,
+    pre_search_selection: Option<String>,
+    // Whether tasks are rendered grouped by project, with collapsible headers.
+    grouped_view: bool,
+    // Project names whose group is collapsed in grouped view.
+    collapsed_groups: std::collections::HashSet<String>,
+    // When set, the component renders into a fixed-height region anchored at the bottom of
+    // `area` instead of filling it, and PTYs size themselves to this height rather than the
+    // full terminal - used for the inline (non-alternate-screen) viewport mode.
+    inline_viewport_height: Option<u16>,
+    // Persists per-task timing/outcome records for the current run so they can be compared
+    // against past runs in the run history popup.
+    history: RunHistoryStore,
+    // Fraction of the horizontal space given to the task list vs. the output area in collapsed
+    // mode, adjustable at runtime via `grow_task_list`/`shrink_task_list`.
+    task_list_split_ratio: f32,
+    // Fraction of the output area given to the first pane vs. the second, when exactly two
+    // panes are visible - adjustable via `grow_first_pane`/`shrink_first_pane`.
+    pane_split_ratio: f32,
+    // Filter text, selection and scroll offset saved per tab (keyed the same way as
+    // `active_tab`), so switching tabs restores that tab's state instead of sharing one
+    // global filter/scroll across all of them.
+    tab_state: std::collections::HashMap<Option<usize>, TabState>,
+}
+
+/// A tab's saved UI state, restored when the user switches back to it via `next_tab`/`previous_tab`.
+#[derive(Debug, Clone, Default)]
+struct TabState {
+    filter_text: String,
+    filter_persisted: bool,
+    selected_task_name: Option<String>,
+    scroll_offset: usize,
+}
+
+/// Fuzzy-ranked matches for the active search query, independent of `filter_text`.
+#[derive(Default)]
+struct SearchResults {
+    query: String,
+    matches: Vec<String>,
+    cursor: usize,
+}
+
+/// Which optional columns of the task table are currently shown. The status icon and task
+/// name columns are always present and aren't represented here.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct TableColumns {
+    cache: bool,
+    duration: bool,
+}
+
+impl TableColumns {
+    const NONE: TableColumns = TableColumns {
+        cache: false,
+        duration: false,
+    };
+
+    /// Whether neither optional column fits, so headers/messages should fall back to their
+    /// shorter wording.
+    fn is_narrow(&self) -> bool {
+        !self.cache && !self.duration
+    }
+}
+
+/// Decides which optional columns (Cache, Duration) fit in the available width, dropping the
+/// lowest-priority one (Cache) first, so new columns only need an entry here rather than a new
+/// `if width < N` branch at every call site that builds a row.
+struct TableBuilder;
+
+impl TableBuilder {
+    const STATUS_WIDTH: u16 = 8;
+    const NAME_MIN_WIDTH: u16 = 20;
+    const CACHE_WIDTH: u16 = 30;
+    const DURATION_WIDTH: u16 = 15;
+
+    /// Works out which optional columns fit in `available_width`, keeping Duration over Cache
+    /// when there's only room for one.
+    fn columns(available_width: u16) -> TableColumns {
+        let mandatory_width = Self::STATUS_WIDTH + Self::NAME_MIN_WIDTH;
+
+        if available_width >= mandatory_width + Self::CACHE_WIDTH + Self::DURATION_WIDTH {
+            TableColumns {
+                cache: true,
+                duration: true,
+            }
+        } else if available_width >= mandatory_width + Self::DURATION_WIDTH {
+            TableColumns {
+                cache: false,
+                duration: true,
+            }
+        } else {
+            TableColumns::NONE
+        }
+    }
+
+    /// The table-wide column width constraints matching whichever columns are active.
+    fn constraints(columns: TableColumns) -> Vec<Constraint> {
+        let mut constraints = vec![
+            Constraint::Length(Self::STATUS_WIDTH),
+            Constraint::Fill(1), // Task name
+        ];
+        if columns.cache {
+            constraints.push(Constraint::Length(Self::CACHE_WIDTH));
+        }
+        if columns.duration {
+            constraints.push(Constraint::Length(Self::DURATION_WIDTH));
+        }
+        constraints
+    }
 }
 
 /// Represents an individual task with its current state and execution details.
@@ -104,25 +242,27 @@ impl TaskItem {
         }
     }
 
+    /// Persists this task's full output (including scrollback) to `TUI_LOG_DIR`, so it can be
+    /// reloaded by `get_cached_output` on a later run against the same workspace.
     fn save_output(&self) -> io::Result<()> {
-        // if let Some(pty) = &self.pty {
-        //     if let Some(screen) = pty.get_screen() {
-        //         // Create tmp directory if it doesn't exist
-        //         let tmp_dir = PathBuf::from("tmp");
-        //         fs::create_dir_all(&tmp_dir)?;
-
-        //         // Create a file for this task's output
-        //         let file_name = format!("{}.txt", self.name.replace(':', "-"));
-        //         let file_path = tmp_dir.join(file_name);
-        //         let mut file = File::create(file_path)?;
-
-        //         // Write the full output including scrollback
-        //         file.write_all(&screen.all_contents_formatted())?;
-        //     }
-        // }
+        if let Some(pty) = &self.pty {
+            if let Some(screen) = pty.get_screen() {
+                let log_dir = PathBuf::from(TUI_LOG_DIR);
+                fs::create_dir_all(&log_dir)?;
+
+                let file_path = log_dir.join(Self::log_file_name(&self.name));
+                let mut file = File::create(file_path)?;
+
+                file.write_all(&screen.all_contents_formatted())?;
+            }
+        }
         Ok(())
     }
 
+    fn log_file_name(task_name: &str) -> String {
+        format!("{}.txt", task_name.replace(':', "-"))
+    }
+
     pub fn update_status(&mut self) {
         if let Some(pty) = &self.pty {
             if !self.continuous {
@@ -139,37 +279,33 @@ impl TaskItem {
                             self.duration = utils::format_duration_since(start, now);
                         }
 
-                        // Check for cached output first
-                        if let Some(_) = self.get_cached_output() {
-                            let cache_status = TaskStatus::random_cache_status();
-                            self.cache = match cache_status {
-                                TaskStatus::LocalCacheKeptExisting => {
-                                    CACHE_STATUS_LOCAL_KEPT_EXISTING.to_string()
-                                }
-                                TaskStatus::LocalCache => CACHE_STATUS_LOCAL.to_string(),
-                                TaskStatus::RemoteCache => CACHE_STATUS_REMOTE.to_string(),
-                                _ => unreachable!(), // random_cache_status() only returns cache variants
-                            };
-                            self.status = cache_status;
+                        // A real cache hit never reaches this tick-driven completion path -
+                        // it's reported straight from the task runner's `TaskResult` via
+                        // `complete_cached_task`/`end_tasks` instead, since a cached task never
+                        // spawns a PTY to exit in the first place. If persisted output exists
+                        // for this task (e.g. reattached to a fresh PTY after restarting the
+                        // TUI mid-run), reload it for display only - it doesn't change the
+                        // exit-code-derived status below.
+                        if let Some(cached_output) = self.get_cached_output() {
+                            self.terminal_output = String::from_utf8_lossy(&cached_output).to_string();
+                        }
+
+                        let new_status = if exit_code == 0 {
+                            TaskStatus::Success
                         } else {
-                            // No cache hit, set regular success/failure
-                            let new_status = if exit_code == 0 {
-                                TaskStatus::Success
-                            } else {
-                                TaskStatus::Failure
-                            };
+                            TaskStatus::Failure
+                        };
 
-                            // Save output if task passed
-                            if new_status == TaskStatus::Success {
-                                if let Err(_e) = self.save_output() {
-                                    // log_debug(&format!("Failed to save task output: {}", e));
-                                }
+                        // Save output if task passed
+                        if new_status == TaskStatus::Success {
+                            if let Err(_e) = self.save_output() {
+                                // log_debug(&format!("Failed to save task output: {}", e));
                             }
-
-                            // Update cache status to "miss" since this was a fresh run
-                            self.cache = CACHE_STATUS_MISS.to_string();
-                            self.status = new_status;
                         }
+
+                        // Update cache status to "miss" since this was a fresh run
+                        self.cache = CACHE_STATUS_MISS.to_string();
+                        self.status = new_status;
                     }
                 }
             } else {
@@ -178,24 +314,26 @@ impl TaskItem {
         }
     }
 
+    /// Returns previously-persisted output for this task, either from memory (if already
+    /// loaded this run) or from `TUI_LOG_DIR` (if it was persisted on a prior run).
     fn get_cached_output(&self) -> Option<Vec<u8>> {
-        None
-        // if self.terminal_output.is_empty() {
-        //     let tmp_dir = PathBuf::from("tmp");
-        //     let file_name = format!("{}.txt", self.name.replace(':', "-"));
-        //     let file_path = tmp_dir.join(file_name);
-
-        //     if file_path.exists() {
-        //         fs::read(file_path).ok()
-        //     } else {
-        //         None
-        //     }
-        // } else {
-        //     Some(self.terminal_output.as_bytes().to_vec())
-        // }
-    }
-
-    pub fn start_task(&mut self, command_lookup: &CommandLookup) -> io::Result<bool> {
+        if !self.terminal_output.is_empty() {
+            return Some(self.terminal_output.as_bytes().to_vec());
+        }
+
+        let file_path = PathBuf::from(TUI_LOG_DIR).join(Self::log_file_name(&self.name));
+        if file_path.exists() {
+            fs::read(file_path).ok()
+        } else {
+            None
+        }
+    }
+
+    pub fn start_task(
+        &mut self,
+        command_lookup: &CommandLookup,
+        inline_viewport_height: Option<u16>,
+    ) -> io::Result<bool> {
         if matches!(self.status, TaskStatus::NotStarted) {
             let old_status = self.status;
             self.status = TaskStatus::InProgress;
@@ -208,9 +346,10 @@ impl TaskItem {
                     .as_millis(),
             );
 
-            // Get terminal size
-            let terminal_size = crossterm::terminal::size().unwrap_or((80, 24));
-            let (width, height) = terminal_size;
+            // Get terminal size, capping the height to the inline viewport budget (if any) so
+            // the PTY doesn't size itself for rows that sit outside the anchored region.
+            let (width, full_height) = crossterm::terminal::size().unwrap_or((80, 24));
+            let height = inline_viewport_height.unwrap_or(full_height);
 
             // Calculate dimensions using the same logic as handle_resize
             let output_width = (width / 3) * 2; // Two-thirds of width for PTY panes
@@ -233,13 +372,19 @@ impl TaskItem {
         }
     }
 
-    pub fn update_output(&mut self, output: &str, status: TaskStatus) {
+    pub fn update_output(
+        &mut self,
+        output: &str,
+        status: TaskStatus,
+        inline_viewport_height: Option<u16>,
+    ) {
         self.terminal_output = output.to_string();
         self.status = status;
 
-        // Get terminal size
-        let terminal_size = crossterm::terminal::size().unwrap_or((80, 24));
-        let (width, height) = terminal_size;
+        // Get terminal size, capping the height to the inline viewport budget (if any) so
+        // the PTY doesn't size itself for rows that sit outside the anchored region.
+        let (width, full_height) = crossterm::terminal::size().unwrap_or((80, 24));
+        let height = inline_viewport_height.unwrap_or(full_height);
 
         // Calculate dimensions using the same logic as handle_resize
         let output_width = (width / 3) * 2; // Two-thirds of width for PTY panes
@@ -306,13 +451,21 @@ impl std::str::FromStr for TaskStatus {
 }
 
 impl TaskStatus {
-    fn random_cache_status() -> Self {
-        use rand::Rng;
-        let mut rng = rand::rng();
-        match rng.random_range(0..3) {
-            0 => Self::LocalCacheKeptExisting,
-            1 => Self::LocalCache,
-            _ => Self::RemoteCache,
+    /// Whether this status represents a task that has finished running, as opposed to one
+    /// that's still queued or in progress - used to detect when a task's history record
+    /// should be persisted.
+    fn is_terminal(&self) -> bool {
+        !matches!(self, TaskStatus::NotStarted | TaskStatus::InProgress)
+    }
+
+    /// The display label for a cache-hit status, as reported by the task runner. `None` for a
+    /// non-cache status (a task that actually ran, or one still in flight).
+    fn cache_label(&self) -> Option<String> {
+        match self {
+            TaskStatus::LocalCacheKeptExisting => Some(CACHE_STATUS_LOCAL_KEPT_EXISTING.to_string()),
+            TaskStatus::LocalCache => Some(CACHE_STATUS_LOCAL.to_string()),
+            TaskStatus::RemoteCache => Some(CACHE_STATUS_REMOTE.to_string()),
+            _ => None,
         }
     }
 }
@@ -344,19 +497,98 @@ impl TasksList {
             scroll_offset: 0,
             scrollbar_state: ScrollbarState::default(),
             content_height: 0,
-            pane_tasks: [None, None],
+            pane_tasks: vec![None; DEFAULT_PANE_COUNT],
             focused_pane: None,
             last_task_start: None,
             queued_tasks,
             is_dimmed: false,
             spacebar_mode: false,
-            terminal_pane_data: [TerminalPaneData::new(), TerminalPaneData::new()],
+            terminal_pane_data: (0..DEFAULT_PANE_COUNT).map(|_| TerminalPaneData::new()).collect(),
             command_lookup,
             target_names,
             task_list_hidden: false,
+            pre_filter_selection: None,
+            filter_match_indices: std::collections::HashMap::new(),
+            active_tab: None,
+            search_mode: false,
+            search_query: String::new(),
+            search_results: SearchResults::default(),
+            pre_search_selection: None,
+            grouped_view: false,
+            collapsed_groups: std::collections::HashSet::new(),
+            inline_viewport_height: None,
+            history: RunHistoryStore::new(),
+            task_list_split_ratio: Self::DEFAULT_TASK_LIST_SPLIT_RATIO,
+            pane_split_ratio: Self::DEFAULT_PANE_SPLIT_RATIO,
+            tab_state: std::collections::HashMap::new(),
+            color_mode: ColorMode::Auto,
         }
     }
 
+    /// The project segment of a task id, e.g. `"my-app"` for `"my-app:build"`.
+    fn task_project(task_name: &str) -> &str {
+        task_name.splitn(2, ':').next().unwrap_or(task_name)
+    }
+
+    /// The target segment of a task id, e.g. `"build"` for `"my-app:build"`.
+    fn task_target(task_name: &str) -> Option<&str> {
+        task_name.splitn(2, ':').nth(1)
+    }
+
+    /// Switches to the next tab (All -> first target -> ... -> last target -> All).
+    pub fn next_tab(&mut self) {
+        if self.target_names.is_empty() {
+            return;
+        }
+        self.save_active_tab_state();
+        self.active_tab = match self.active_tab {
+            None => Some(0),
+            Some(idx) if idx + 1 < self.target_names.len() => Some(idx + 1),
+            Some(_) => None,
+        };
+        self.restore_active_tab_state();
+    }
+
+    /// Switches to the previous tab (All -> last target -> ... -> first target -> All).
+    pub fn previous_tab(&mut self) {
+        if self.target_names.is_empty() {
+            return;
+        }
+        self.save_active_tab_state();
+        self.active_tab = match self.active_tab {
+            None => Some(self.target_names.len() - 1),
+            Some(0) => None,
+            Some(idx) => Some(idx - 1),
+        };
+        self.restore_active_tab_state();
+    }
+
+    /// Saves the filter text, selection and scroll offset of the tab we're about to leave, keyed
+    /// by the (pre-switch) value of `active_tab`.
+    fn save_active_tab_state(&mut self) {
+        let state = TabState {
+            filter_text: self.filter_text.clone(),
+            filter_persisted: self.filter_persisted,
+            selected_task_name: self.selection_manager.get_selected_task_name(),
+            scroll_offset: self.scroll_offset,
+        };
+        self.tab_state.insert(self.active_tab, state);
+    }
+
+    /// Restores the filter text, selection and scroll offset previously saved for the
+    /// now-active tab, or resets to defaults if this tab has never been visited before.
+    fn restore_active_tab_state(&mut self) {
+        let state = self.tab_state.get(&self.active_tab).cloned().unwrap_or_default();
+        self.filter_text = state.filter_text;
+        self.filter_persisted = state.filter_persisted;
+        self.apply_filter();
+        if let Some(task_name) = state.selected_task_name {
+            self.selection_manager.select_task_by_name(&task_name);
+        }
+        self.scroll_offset = state.scroll_offset;
+        self.scrollbar_state = self.scrollbar_state.position(self.scroll_offset);
+    }
+
     /// Moves the selection to the next task in the list.
     /// If in spacebar mode, updates the output pane to show the newly selected task.
     pub fn next(&mut self) {
@@ -462,6 +694,164 @@ impl TasksList {
         entries
     }
 
+    /// Creates a list of task entries grouped by project, with a synthetic header entry
+    /// before each project's tasks. Collapsed projects contribute only their header.
+    fn create_grouped_entries(&self, filtered_names: &[String]) -> Vec<Option<String>> {
+        let mut projects: Vec<&str> = Vec::new();
+        let mut by_project: std::collections::HashMap<&str, Vec<String>> =
+            std::collections::HashMap::new();
+
+        for task_name in filtered_names {
+            let project = Self::task_project(task_name);
+            by_project
+                .entry(project)
+                .or_default()
+                .push(task_name.clone());
+            if !projects.contains(&project) {
+                projects.push(project);
+            }
+        }
+
+        let mut entries = Vec::new();
+        for project in projects {
+            entries.push(Some(format!("{}{}", GROUP_HEADER_PREFIX, project)));
+            if !self.collapsed_groups.contains(project) {
+                if let Some(tasks) = by_project.get(project) {
+                    entries.extend(tasks.iter().cloned().map(Some));
+                }
+            }
+        }
+
+        entries
+    }
+
+    /// Trailing empty cells needed to pad a non-task row out to match however many optional
+    /// columns (Cache, Duration) are currently visible.
+    fn empty_column_cells<'a>(columns: TableColumns) -> Vec<Cell<'a>> {
+        let mut cells = Vec::new();
+        if columns.cache {
+            cells.push(Cell::from(""));
+        }
+        if columns.duration {
+            cells.push(Cell::from(""));
+        }
+        cells
+    }
+
+    /// Whether an entry from the selection manager is a group header rather than a task id.
+    fn is_group_header(name: &str) -> bool {
+        name.starts_with(GROUP_HEADER_PREFIX)
+    }
+
+    /// Strips the sentinel prefix from a group header entry, returning the project name.
+    fn group_header_project(name: &str) -> &str {
+        name.strip_prefix(GROUP_HEADER_PREFIX).unwrap_or(name)
+    }
+
+    /// Aggregates the status and completion counts for every task belonging to a project.
+    fn group_summary(&self, project: &str) -> (TaskStatus, usize, usize) {
+        let project_tasks: Vec<&TaskItem> = self
+            .tasks
+            .iter()
+            .filter(|t| Self::task_project(&t.name) == project)
+            .collect();
+
+        let total = project_tasks.len();
+        let completed = project_tasks
+            .iter()
+            .filter(|t| {
+                matches!(
+                    t.status,
+                    TaskStatus::Success
+                        | TaskStatus::Failure
+                        | TaskStatus::Skipped
+                        | TaskStatus::LocalCache
+                        | TaskStatus::LocalCacheKeptExisting
+                        | TaskStatus::RemoteCache
+                )
+            })
+            .count();
+
+        let status = if project_tasks
+            .iter()
+            .any(|t| matches!(t.status, TaskStatus::InProgress))
+        {
+            TaskStatus::InProgress
+        } else if project_tasks
+            .iter()
+            .any(|t| matches!(t.status, TaskStatus::Failure))
+        {
+            TaskStatus::Failure
+        } else if project_tasks
+            .iter()
+            .any(|t| matches!(t.status, TaskStatus::NotStarted))
+        {
+            TaskStatus::NotStarted
+        } else {
+            TaskStatus::Success
+        };
+
+        (status, completed, total)
+    }
+
+    /// Completed vs. total task counts and whether any task has failed, for the run-wide
+    /// progress gauge in the title bar.
+    fn task_progress_summary(&self) -> (usize, usize, bool) {
+        let total = self.tasks.len();
+        let completed = self.tasks.iter().filter(|t| t.status.is_terminal()).count();
+        let has_failures = self.tasks.iter().any(|t| t.status == TaskStatus::Failure);
+        (completed, total, has_failures)
+    }
+
+    /// Parses a task's rendered duration string (e.g. `"350ms"`, `"1.2s"`, `"<1ms"`) back into
+    /// milliseconds for the duration sparkline - returns `None` for tasks with no duration yet
+    /// (`""`) or continuous tasks (`"Continuous"`), which don't have a meaningful duration.
+    fn parse_duration_ms(duration: &str) -> Option<u64> {
+        let trimmed = duration.trim_start_matches('<');
+        if let Some(ms) = trimmed.strip_suffix("ms") {
+            ms.parse::<f64>().ok().map(|v| v.round() as u64)
+        } else if let Some(secs) = trimmed.strip_suffix('s') {
+            secs.parse::<f64>().ok().map(|v| (v * 1000.0).round() as u64)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the currently selected row is a group header rather than a task.
+    pub fn selected_is_group_header(&self) -> bool {
+        self.selection_manager
+            .get_selected_task_name()
+            .map(|name| Self::is_group_header(&name))
+            .unwrap_or(false)
+    }
+
+    /// Toggles whether the task list is organized into collapsible per-project groups.
+    pub fn toggle_grouped_view(&mut self) {
+        self.grouped_view = !self.grouped_view;
+        self.apply_filter();
+        self.reset_scroll();
+    }
+
+    /// Expands or collapses the project group under the current selection, preserving it.
+    pub fn toggle_selected_group(&mut self) {
+        let Some(name) = self.selection_manager.get_selected_task_name() else {
+            return;
+        };
+        if !Self::is_group_header(&name) {
+            return;
+        }
+        let project = Self::group_header_project(&name).to_string();
+
+        if self.collapsed_groups.contains(&project) {
+            self.collapsed_groups.remove(&project);
+        } else {
+            self.collapsed_groups.insert(project.clone());
+        }
+
+        self.apply_filter();
+        self.selection_manager.select_task_by_name(&name);
+    }
+
     /// Recalculates the number of items that can be displayed per page based on the available height.
     /// Updates the selection manager with the new page size and current entries.
     fn recalculate_pages(&mut self, available_height: u16) {
@@ -469,8 +859,12 @@ impl TasksList {
         self.selection_manager
             .set_items_per_page(available_height as usize);
 
-        // Update entries in selection manager with separator
-        let entries = self.create_entries_with_separator(&self.filtered_names);
+        // Update entries in selection manager, grouped by project or with status separators
+        let entries = if self.grouped_view {
+            self.create_grouped_entries(&self.filtered_names)
+        } else {
+            self.create_entries_with_separator(&self.filtered_names)
+        };
         self.selection_manager.update_entries(entries);
     }
 
@@ -479,10 +873,11 @@ impl TasksList {
     pub fn enter_filter_mode(&mut self) {
         if !self.filter_text.is_empty() && !self.filter_persisted {
             // If we have filter text and it's not persisted, pressing / should persist it
-            self.filter_persisted = true;
-            self.filter_mode = false;
+            self.confirm_filter();
         } else {
-            // Otherwise enter normal filter mode
+            // Otherwise enter normal filter mode, remembering what was selected so we can
+            // restore it if the search is cancelled rather than confirmed.
+            self.pre_filter_selection = self.selection_manager.get_selected_task_name();
             self.filter_persisted = false;
             self.filter_mode = true;
         }
@@ -494,12 +889,46 @@ impl TasksList {
         self.filter_persisted = false;
     }
 
+    /// Confirms the in-progress search, persisting the filter text and leaving filter mode.
+    /// Mirrors pressing `/` a second time, but triggered directly (e.g. by Enter).
+    pub fn confirm_filter(&mut self) {
+        self.filter_persisted = true;
+        self.filter_mode = false;
+        self.pre_filter_selection = None;
+    }
+
+    /// Cancels an in-progress search, clearing the filter text and restoring whichever task
+    /// was selected before search mode was entered. Has no effect on an already-persisted
+    /// filter - use `clear_filter` for that.
+    pub fn cancel_filter(&mut self) {
+        if !self.filter_mode {
+            return;
+        }
+
+        self.filter_mode = false;
+        self.filter_text.clear();
+        self.apply_filter();
+
+        if let Some(task_name) = self.pre_filter_selection.take() {
+            self.selection_manager.select_task_by_name(&task_name);
+        }
+    }
+
     /// Clears the current filter and resets filter-related state.
+    /// Keeps the currently selected task selected if it's still present once the full,
+    /// unfiltered list is restored, rather than jumping back to the top of the list.
     pub fn clear_filter(&mut self) {
+        let selected_task = self.selection_manager.get_selected_task_name();
+
         self.filter_mode = false;
         self.filter_persisted = false;
         self.filter_text.clear();
+        self.pre_filter_selection = None;
         self.apply_filter();
+
+        if let Some(task_name) = selected_task {
+            self.selection_manager.select_task_by_name(&task_name);
+        }
     }
 
     /// Adds a character to the filter text if not in persisted mode.
@@ -528,27 +957,78 @@ impl TasksList {
         self.apply_filter();
     }
 
+    /// Sets the filter text directly and persists it, as if the user had typed it and pressed
+    /// `/` to confirm - used to restore a layout preset's `default_filter` on launch.
+    pub fn set_filter_text(&mut self, text: String) {
+        self.filter_text = text;
+        self.filter_persisted = !self.filter_text.is_empty();
+        self.filter_mode = false;
+        self.apply_filter();
+    }
+
+    /// All known task names, for validating a layout preset's pane assignments against the
+    /// tasks that actually exist in this run.
+    pub fn task_names(&self) -> Vec<String> {
+        self.tasks.iter().map(|t| t.name.clone()).collect()
+    }
+
+    /// Status of the currently selected task, if one is selected - used to decide whether a
+    /// "launch after exit" action (e.g. opening a failed task in `$EDITOR`) makes sense.
+    pub fn selected_task_status(&self) -> Option<TaskStatus> {
+        let task_name = self.selection_manager.get_selected_task_name()?;
+        self.tasks.iter().find(|t| t.name == task_name).map(|t| t.status)
+    }
+
+    /// Path to `task_name`'s captured-output log file, mirroring `TaskItem::save_output`'s
+    /// naming - used to open a task's output in an external program after the TUI exits.
+    pub fn task_log_file_path(task_name: &str) -> PathBuf {
+        PathBuf::from(TUI_LOG_DIR).join(TaskItem::log_file_name(task_name))
+    }
+
     /// Applies the current filter text to the task list.
     /// Updates filtered tasks and selection manager entries.
     /// NEEDS ANALYSIS: Consider splitting the filter logic from the UI update logic.
     pub fn apply_filter(&mut self) {
+        let in_active_tab = |task_name: &str| match self.active_tab {
+            None => true,
+            Some(idx) => Self::task_target(task_name) == self.target_names.get(idx).map(|s| s.as_str()),
+        };
+
+        self.filter_match_indices.clear();
+
         if self.filter_text.is_empty() {
-            self.filtered_names = self.tasks.iter().map(|t| t.name.clone()).collect();
-        } else {
             self.filtered_names = self
                 .tasks
                 .iter()
-                .filter(|item| {
-                    item.name
-                        .to_lowercase()
-                        .contains(&self.filter_text.to_lowercase())
-                })
+                .filter(|t| in_active_tab(&t.name))
                 .map(|t| t.name.clone())
                 .collect();
+        } else {
+            let mut scored: Vec<(i64, String, Vec<usize>)> = self
+                .tasks
+                .iter()
+                .filter(|item| in_active_tab(&item.name))
+                .filter_map(|item| {
+                    Self::fuzzy_match(&item.name, &self.filter_text)
+                        .map(|(score, indices)| (score, item.name.clone(), indices))
+                })
+                .collect();
+
+            // Highest score (best match) first; ties keep the original task order.
+            scored.sort_by(|a, b| b.0.cmp(&a.0));
+
+            self.filtered_names = scored.iter().map(|(_, name, _)| name.clone()).collect();
+            for (_, name, indices) in scored {
+                self.filter_match_indices.insert(name, indices);
+            }
         }
 
-        // Update entries in selection manager with separator
-        let entries = self.create_entries_with_separator(&self.filtered_names);
+        // Update entries in selection manager, grouped by project or with status separators
+        let entries = if self.grouped_view {
+            self.create_grouped_entries(&self.filtered_names)
+        } else {
+            self.create_entries_with_separator(&self.filtered_names)
+        };
         self.selection_manager.update_entries(entries);
 
         // Update spacebar mode output if active
@@ -561,6 +1041,173 @@ impl TasksList {
         }
     }
 
+    /// Scores a task name against a filter query for `apply_filter`, using a bespoke
+    /// left-to-right subsequence match rather than `SkimMatcherV2` (used by search mode below)
+    /// so filtering can cheaply report back the matched character indices for highlighting.
+    /// Returns `None` if `query` isn't a subsequence of `candidate` at all.
+    fn fuzzy_match(candidate: &str, query: &str) -> Option<(i64, Vec<usize>)> {
+        if query.is_empty() {
+            return Some((0, Vec::new()));
+        }
+
+        const BASE_SCORE: i64 = 10;
+        const CONTIGUOUS_BONUS: i64 = 8;
+        const START_BONUS: i64 = 15;
+        const BOUNDARY_BONUS: i64 = 12;
+        const GAP_PENALTY: i64 = 1;
+
+        let candidate_chars: Vec<char> = candidate.chars().collect();
+        let query_chars: Vec<char> = query.chars().collect();
+
+        let mut score = 0i64;
+        let mut matched_indices = Vec::with_capacity(query_chars.len());
+        let mut candidate_idx = 0;
+        let mut last_matched_idx: Option<usize> = None;
+
+        for &q in &query_chars {
+            let q_lower = q.to_ascii_lowercase();
+            let mut found = None;
+            while candidate_idx < candidate_chars.len() {
+                if candidate_chars[candidate_idx].to_ascii_lowercase() == q_lower {
+                    found = Some(candidate_idx);
+                    break;
+                }
+                candidate_idx += 1;
+            }
+            let idx = found?;
+
+            score += BASE_SCORE;
+            if idx == 0 {
+                score += START_BONUS;
+            }
+            if idx > 0 && matches!(candidate_chars[idx - 1], '-' | ':' | '/' | '_') {
+                score += BOUNDARY_BONUS;
+            }
+            match last_matched_idx {
+                Some(prev) if idx == prev + 1 => score += CONTIGUOUS_BONUS,
+                Some(prev) => score -= GAP_PENALTY * (idx - prev - 1) as i64,
+                None => {}
+            }
+
+            matched_indices.push(idx);
+            last_matched_idx = Some(idx);
+            candidate_idx += 1;
+        }
+
+        Some((score, matched_indices))
+    }
+
+    /// Enters search mode, stashing the current selection so Esc can restore it.
+    /// Unlike filter mode, search never hides rows - it only ranks and highlights matches.
+    pub fn enter_search_mode(&mut self) {
+        self.pre_search_selection = self.selection_manager.get_selected_task_name();
+        self.search_mode = true;
+        self.search_query.clear();
+        self.search_results = SearchResults::default();
+    }
+
+    /// Whether there are live search matches to cycle through with n/N, even after the
+    /// search input itself has lost focus back to the task list.
+    pub fn has_active_search(&self) -> bool {
+        !self.search_results.matches.is_empty()
+    }
+
+    /// Cancels search mode, restoring whichever task was selected beforehand.
+    pub fn cancel_search(&mut self) {
+        self.search_mode = false;
+        self.search_query.clear();
+        self.search_results = SearchResults::default();
+
+        if let Some(task_name) = self.pre_search_selection.take() {
+            self.selection_manager.select_task_by_name(&task_name);
+        }
+    }
+
+    /// Appends a character to the search query and re-runs the fuzzy match.
+    pub fn add_search_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.run_search();
+    }
+
+    /// Removes the last character from the search query and re-runs the fuzzy match.
+    pub fn remove_search_char(&mut self) {
+        self.search_query.pop();
+        self.run_search();
+    }
+
+    /// Fuzzy-ranks every task name against the search query (subsequence match, favoring
+    /// contiguous and start-of-word hits via `SkimMatcherV2`) and jumps to the best match.
+    fn run_search(&mut self) {
+        if self.search_query.is_empty() {
+            self.search_results = SearchResults::default();
+            return;
+        }
+
+        let matcher = SkimMatcherV2::default();
+        let mut scored: Vec<(i64, &str)> = self
+            .tasks
+            .iter()
+            .filter_map(|item| {
+                matcher
+                    .fuzzy_match(&item.name, &self.search_query)
+                    .map(|score| (score, item.name.as_str()))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+
+        self.search_results = SearchResults {
+            query: self.search_query.clone(),
+            matches: scored.into_iter().map(|(_, name)| name.to_string()).collect(),
+            cursor: 0,
+        };
+
+        if let Some(task_name) = self.search_results.matches.first().cloned() {
+            self.selection_manager.select_task_by_name(&task_name);
+        }
+    }
+
+    /// Jumps the selection to the next search match, scrolling it into view, wrapping around.
+    pub fn next_match(&mut self) {
+        if self.search_results.matches.is_empty() {
+            return;
+        }
+        self.search_results.cursor = (self.search_results.cursor + 1) % self.search_results.matches.len();
+        let task_name = self.search_results.matches[self.search_results.cursor].clone();
+        self.selection_manager.select_task_by_name(&task_name);
+    }
+
+    /// Jumps the selection to the previous search match, scrolling it into view, wrapping around.
+    pub fn previous_match(&mut self) {
+        if self.search_results.matches.is_empty() {
+            return;
+        }
+        self.search_results.cursor = (self.search_results.cursor + self.search_results.matches.len() - 1)
+            % self.search_results.matches.len();
+        let task_name = self.search_results.matches[self.search_results.cursor].clone();
+        self.selection_manager.select_task_by_name(&task_name);
+    }
+
+    /// Character indices of `task_name` that matched the active search query, for highlighting.
+    fn search_match_indices(&self, task_name: &str) -> Option<Vec<usize>> {
+        if !self.search_mode || self.search_results.query.is_empty() {
+            return None;
+        }
+        let matcher = SkimMatcherV2::default();
+        matcher
+            .fuzzy_indices(task_name, &self.search_results.query)
+            .map(|(_, indices)| indices)
+    }
+
+    /// Matched character indices for `task_name` from the active filter's fuzzy match, if any -
+    /// used to bold/underline the matched characters in the task list, mirroring
+    /// `search_match_indices` above for search mode.
+    fn filter_match_indices(&self, task_name: &str) -> Option<&Vec<usize>> {
+        if self.filter_text.is_empty() {
+            return None;
+        }
+        self.filter_match_indices.get(task_name)
+    }
+
     pub fn set_focus(&mut self, focus: Focus) {
         self.focus = focus;
         // Clear multi-output focus when returning to task list
@@ -614,7 +1261,8 @@ impl TasksList {
                 self.spacebar_mode = false;
             } else {
                 // Show current task in pane 1 in spacebar mode
-                self.pane_tasks = [Some(task_name.clone()), None];
+                self.pane_tasks.iter_mut().for_each(|t| *t = None);
+                self.pane_tasks[0] = Some(task_name.clone());
                 self.focused_pane = None;
                 self.spacebar_mode = true; // Enter spacebar mode
             }
@@ -628,18 +1276,237 @@ impl TasksList {
 
     /// Clears all output panes and resets their associated state.
     pub fn clear_all_panes(&mut self) {
-        self.pane_tasks = [None, None];
+        self.pane_tasks.iter_mut().for_each(|t| *t = None);
         self.focused_pane = None;
         self.focus = Focus::TaskList;
         self.spacebar_mode = false;
+        self.clear_search_on_blurred_pane();
+        self.persist_pane_session();
+    }
+
+    const DEFAULT_TASK_LIST_SPLIT_RATIO: f32 = 1.0 / 3.0;
+    const DEFAULT_PANE_SPLIT_RATIO: f32 = 0.5;
+    // How much a single Ctrl-Left/Right keypress shifts a split ratio.
+    const SPLIT_RESIZE_STEP: f32 = 0.05;
+    // A pane can never be resized below this many columns/rows.
+    const MIN_SPLIT_DIMENSION: u16 = 10;
+
+    /// Grows the task list's share of the width by `SPLIT_RESIZE_STEP`, shrinking the output
+    /// area by the same amount, snapping back if either side would fall below
+    /// `MIN_SPLIT_DIMENSION` columns at the current box width.
+    pub fn grow_task_list(&mut self) {
+        self.task_list_split_ratio =
+            Self::clamp_split_ratio(self.task_list_split_ratio + Self::SPLIT_RESIZE_STEP, self.last_box_area.width);
+    }
+
+    /// Shrinks the task list's share of the width, growing the output area by the same amount.
+    pub fn shrink_task_list(&mut self) {
+        self.task_list_split_ratio =
+            Self::clamp_split_ratio(self.task_list_split_ratio - Self::SPLIT_RESIZE_STEP, self.last_box_area.width);
+    }
+
+    /// Grows the first pane's share of the output area, shrinking its neighbor by the same
+    /// amount, when exactly two panes are visible.
+    pub fn grow_first_pane(&mut self) {
+        self.pane_split_ratio =
+            Self::clamp_split_ratio(self.pane_split_ratio + Self::SPLIT_RESIZE_STEP, self.output_area_width());
+    }
+
+    /// Shrinks the first pane's share of the output area, growing its neighbor by the same
+    /// amount, when exactly two panes are visible.
+    pub fn shrink_first_pane(&mut self) {
+        self.pane_split_ratio =
+            Self::clamp_split_ratio(self.pane_split_ratio - Self::SPLIT_RESIZE_STEP, self.output_area_width());
+    }
+
+    /// Clamps a candidate split ratio so that neither side of a `total_width`-wide divider
+    /// would fall below `MIN_SPLIT_DIMENSION`, snapping back to the nearest valid ratio instead
+    /// of applying a resize that would collapse a pane.
+    fn clamp_split_ratio(ratio: f32, total_width: u16) -> f32 {
+        if total_width == 0 {
+            return ratio.clamp(0.0, 1.0);
+        }
+        let min_fraction = (Self::MIN_SPLIT_DIMENSION as f32 / total_width as f32).min(0.5);
+        ratio.clamp(min_fraction, 1.0 - min_fraction)
+    }
+
+    /// Computes the width of the output area that `pane_layout` actually divides, mirroring the
+    /// layout math in `draw` - this is `last_box_area.width` minus the task list's share when a
+    /// side-by-side task-list/output split is showing, not the full box width, since the
+    /// pane-split divider only ever moves within the output side of that split.
+    fn output_area_width(&self) -> u16 {
+        if self.task_list_hidden {
+            return self.last_box_area.width;
+        }
+        let collapsed_mode = self.has_visible_panes()
+            || self.last_box_area.width < 100
+            || self.inline_viewport_height.is_some();
+        if !collapsed_mode {
+            return self.last_box_area.width;
+        }
+        let task_list_width =
+            (self.last_box_area.width as f32 * self.task_list_split_ratio) as u16;
+        self.last_box_area.width.saturating_sub(task_list_width)
+    }
+
+    /// Splits `area` into a grid of chunks, one per pane: 1 pane gets the full area, 2 are
+    /// side-by-side (divided according to `pane_split_ratio`), and 3+ wrap into rows of up to
+    /// 2 columns each (evenly split, since `pane_split_ratio` only applies to the 2-pane case).
+    fn pane_layout(area: Rect, num_panes: usize, pane_split_ratio: f32) -> Vec<Rect> {
+        if num_panes <= 1 {
+            return vec![area];
+        }
+
+        let columns = 2.min(num_panes);
+        let rows = num_panes.div_ceil(columns);
+
+        let row_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+            .spacing(1)
+            .split(area);
+
+        let mut chunks = Vec::with_capacity(num_panes);
+        for (row_idx, row_area) in row_chunks.iter().enumerate() {
+            let panes_in_row = ((num_panes - row_idx * columns).min(columns)).max(1);
+            let constraints = if num_panes == 2 && panes_in_row == 2 {
+                let left = (pane_split_ratio * 100.0).round() as u16;
+                vec![
+                    Constraint::Percentage(left),
+                    Constraint::Percentage(100 - left),
+                ]
+            } else {
+                vec![Constraint::Ratio(1, panes_in_row as u32); panes_in_row]
+            };
+            let col_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(constraints)
+                .spacing(2)
+                .split(*row_area);
+            chunks.extend(col_chunks.iter().copied());
+        }
+
+        chunks
+    }
+
+    /// Adds a new, initially-empty output pane that can be assigned a task.
+    pub fn add_pane(&mut self) {
+        self.pane_tasks.push(None);
+        self.terminal_pane_data.push(self.new_terminal_pane_data());
+        self.persist_pane_session();
+    }
+
+    /// Builds a new pane's terminal state with the session's current `color_mode` applied, so
+    /// panes added after a cycle agree with ones that were already open.
+    fn new_terminal_pane_data(&self) -> TerminalPaneData {
+        let mut data = TerminalPaneData::new();
+        data.set_color_mode(self.color_mode);
+        data
+    }
+
+    /// Cycles the PTY-output color fidelity (auto -> truecolor -> 256 -> 16 -> auto) and
+    /// re-applies it to every existing pane, not just ones added afterward.
+    pub fn cycle_color_mode(&mut self) {
+        self.color_mode = self.color_mode.next();
+        for pane in self.terminal_pane_data.iter_mut() {
+            pane.set_color_mode(self.color_mode);
+        }
+    }
+
+    /// Removes the last output pane, clearing pane focus back to the task list if it was focused.
+    pub fn remove_pane(&mut self) {
+        if self.pane_tasks.len() <= 1 {
+            return;
+        }
+        let removed_idx = self.pane_tasks.len() - 1;
+        self.pane_tasks.pop();
+        self.terminal_pane_data.pop();
+
+        if self.focused_pane == Some(removed_idx) {
+            self.focused_pane = None;
+            self.focus = Focus::TaskList;
+        }
+        self.persist_pane_session();
+    }
+
+    /// Closes the currently focused output pane, removing its slot entirely (renumbering the
+    /// panes after it) rather than just clearing its assignment, so the tiled grid shrinks back
+    /// down. A no-op from any other focus, or if it's the only remaining pane.
+    pub fn close_focused_pane(&mut self) {
+        let Focus::TerminalPane(pane_idx) = self.focus else {
+            return;
+        };
+        if self.pane_tasks.len() <= 1 {
+            return;
+        }
+
+        self.pane_tasks.remove(pane_idx);
+        self.terminal_pane_data.remove(pane_idx);
+        self.focused_pane = None;
+        self.focus = Focus::TaskList;
+        self.clear_search_on_blurred_pane();
+        self.persist_pane_session();
+    }
+
+    /// Cycles `pane_idx`'s assigned task forward (or backward) through the full task list,
+    /// wrapping around - swaps what a pane shows without returning to the task list to pin a
+    /// specific task by number.
+    pub fn cycle_pane_task(&mut self, pane_idx: usize, forward: bool) {
+        if pane_idx >= self.pane_tasks.len() {
+            return;
+        }
+        let task_names = self.task_names();
+        if task_names.is_empty() {
+            return;
+        }
+
+        let current_idx = self.pane_tasks[pane_idx]
+            .as_ref()
+            .and_then(|name| task_names.iter().position(|n| n == name));
+
+        let next_idx = match current_idx {
+            Some(idx) if forward => (idx + 1) % task_names.len(),
+            Some(idx) => (idx + task_names.len() - 1) % task_names.len(),
+            None => 0,
+        };
+
+        self.pane_tasks[pane_idx] = Some(task_names[next_idx].clone());
+        self.persist_pane_session();
+    }
+
+    /// Pins the currently selected task into the first empty pane slot, growing the pane set by
+    /// one (via `add_pane`) if every existing slot is already occupied.
+    pub fn pin_selected_task_to_next_free_pane(&mut self) {
+        let Some(task_name) = self.selection_manager.get_selected_task_name() else {
+            return;
+        };
+
+        let free_idx = self.pane_tasks.iter().position(|t| t.is_none());
+        let pane_idx = match free_idx {
+            Some(idx) => idx,
+            None => {
+                self.add_pane();
+                self.pane_tasks.len() - 1
+            }
+        };
+
+        self.pane_tasks[pane_idx] = Some(task_name);
+        self.spacebar_mode = false;
+        self.persist_pane_session();
     }
 
     pub fn assign_current_task_to_pane(&mut self, pane_idx: usize) {
+        if pane_idx >= self.pane_tasks.len() {
+            return;
+        }
         if let Some(task_name) = self.selection_manager.get_selected_task_name() {
             // If we're in spacebar mode and this is pane 0, convert to pinned mode
             if self.spacebar_mode && pane_idx == 0 {
                 self.spacebar_mode = false;
                 self.focused_pane = Some(0);
+                self.focus = Focus::TerminalPane(pane_idx);
+                self.clear_search_on_blurred_pane();
+                self.persist_pane_session();
                 return;
             }
 
@@ -654,6 +1521,8 @@ impl TasksList {
                     self.focus = Focus::TaskList;
                     self.spacebar_mode = false;
                 }
+                self.clear_search_on_blurred_pane();
+                self.persist_pane_session();
                 return;
             }
 
@@ -663,6 +1532,114 @@ impl TasksList {
             self.focus = Focus::TaskList;
             self.spacebar_mode = false; // Exit spacebar mode when pinning
         }
+        self.clear_search_on_blurred_pane();
+        self.persist_pane_session();
+    }
+
+    /// Writes the current pane-to-task assignments to disk so the next invocation in this
+    /// workspace starts with the same panes pinned, without requiring a checked-in
+    /// `LayoutPreset`. Best-effort - a write failure is silently dropped, same as
+    /// `RunHistoryStore`'s own flush.
+    fn persist_pane_session(&self) {
+        let pane_scroll_offsets: Vec<usize> = self
+            .terminal_pane_data
+            .iter()
+            .map(|pane_data| pane_data.scroll_offset())
+            .collect();
+        PaneSessionState::save(&self.pane_tasks, &pane_scroll_offsets);
+    }
+
+    /// Clears any in-progress or active output search on every pane that isn't the currently
+    /// focused one, so a stale highlight set/query doesn't linger once a pane loses focus.
+    fn clear_search_on_blurred_pane(&mut self) {
+        let focused_pane = match self.focus {
+            Focus::TerminalPane(idx) => Some(idx),
+            _ => None,
+        };
+        for (idx, pane_data) in self.terminal_pane_data.iter_mut().enumerate() {
+            if Some(idx) != focused_pane {
+                pane_data.clear_search();
+            }
+        }
+    }
+
+    /// Gives the `App` event loop read/write access to a specific pane's terminal state, so key
+    /// and mouse handling for `Focus::TerminalPane` can live in `handle_event` without `App`
+    /// keeping its own, separate copy of pane state.
+    pub fn terminal_pane_data(&self, pane_idx: usize) -> &TerminalPaneData {
+        &self.terminal_pane_data[pane_idx]
+    }
+
+    pub fn terminal_pane_data_mut(&mut self, pane_idx: usize) -> &mut TerminalPaneData {
+        &mut self.terminal_pane_data[pane_idx]
+    }
+
+    /// Pins each of a layout preset's pane assignments into `pane_tasks`, growing it if the
+    /// preset references a pane index beyond the default pane count, and applies the preset's
+    /// default filter (if any). Assignments naming a task that no longer exists are skipped
+    /// rather than rejecting the whole preset.
+    fn apply_layout_preset(&mut self, preset: &LayoutPreset) {
+        let known_task_names = self.task_names();
+
+        for assignment in preset.valid_assignments(&known_task_names) {
+            if assignment.pane_index >= self.pane_tasks.len() {
+                self.pane_tasks.resize(assignment.pane_index + 1, None);
+            }
+            self.pane_tasks[assignment.pane_index] = Some(assignment.task_name.clone());
+        }
+        while self.terminal_pane_data.len() < self.pane_tasks.len() {
+            self.terminal_pane_data.push(self.new_terminal_pane_data());
+        }
+
+        if let Some(default_filter) = &preset.default_filter {
+            self.set_filter_text(default_filter.clone());
+        }
+    }
+
+    /// Re-reads `nx-tui.json` and re-applies its pane assignments/default filter on top of the
+    /// current session, so a layout preset edited mid-run doesn't require a full restart.
+    pub fn reload_layout_preset(&mut self) {
+        let Some(preset) = LayoutPreset::load() else {
+            return;
+        };
+        self.apply_layout_preset(&preset);
+        self.persist_pane_session();
+    }
+
+    /// Restores the pane layout a new session should start with: a checked-in `nx-tui.json`
+    /// preset takes priority, falling back to whatever pane assignments were last
+    /// auto-persisted so 1/2/.../9 pins survive a restart. Called once from `App::new`.
+    pub fn restore_pane_layout(&mut self) {
+        if let Some(preset) = LayoutPreset::load() {
+            self.apply_layout_preset(&preset);
+            return;
+        }
+
+        let Some(session) = PaneSessionState::load() else {
+            return;
+        };
+        let known_task_names = self.task_names();
+        let mut pane_tasks: Vec<Option<String>> = session
+            .pane_tasks
+            .into_iter()
+            .map(|task_name| task_name.filter(|name| known_task_names.contains(name)))
+            .collect();
+        let mut pane_scroll_offsets = session.pane_scroll_offsets;
+        if pane_tasks.is_empty() {
+            pane_tasks = vec![None; DEFAULT_PANE_COUNT];
+            pane_scroll_offsets.clear();
+        }
+
+        self.terminal_pane_data = (0..pane_tasks.len())
+            .map(|pane_idx| {
+                let mut pane_data = self.new_terminal_pane_data();
+                if let Some(offset) = pane_scroll_offsets.get(pane_idx) {
+                    pane_data.set_scroll_offset(*offset);
+                }
+                pane_data
+            })
+            .collect();
+        self.pane_tasks = pane_tasks;
     }
 
     pub fn focus_next(&mut self) {
@@ -675,22 +1652,24 @@ impl TasksList {
             Focus::TaskList => {
                 // Move to first visible pane
                 if let Some(first_pane) = self.pane_tasks.iter().position(|t| t.is_some()) {
-                    Focus::MultipleOutput(first_pane)
+                    Focus::TerminalPane(first_pane)
                 } else {
                     Focus::TaskList
                 }
             }
-            Focus::MultipleOutput(current_pane) => {
+            Focus::TerminalPane(current_pane) => {
                 // Find next visible pane or go back to task list
-                let next_pane = (current_pane + 1..2).find(|&idx| self.pane_tasks[idx].is_some());
+                let next_pane = (current_pane + 1..self.pane_tasks.len())
+                    .find(|&idx| self.pane_tasks[idx].is_some());
 
                 match next_pane {
-                    Some(pane) => Focus::MultipleOutput(pane),
+                    Some(pane) => Focus::TerminalPane(pane),
                     None => Focus::TaskList,
                 }
             }
-            Focus::HelpPopup => Focus::TaskList,
+            _ => Focus::TaskList,
         };
+        self.clear_search_on_blurred_pane();
     }
 
     pub fn focus_previous(&mut self) {
@@ -702,20 +1681,23 @@ impl TasksList {
         self.focus = match self.focus {
             Focus::TaskList => {
                 // Move to last visible pane
-                if let Some(last_pane) = (0..2).rev().find(|&idx| self.pane_tasks[idx].is_some()) {
-                    Focus::MultipleOutput(last_pane)
+                if let Some(last_pane) = (0..self.pane_tasks.len())
+                    .rev()
+                    .find(|&idx| self.pane_tasks[idx].is_some())
+                {
+                    Focus::TerminalPane(last_pane)
                 } else {
                     Focus::TaskList
                 }
             }
-            Focus::MultipleOutput(current_pane) => {
+            Focus::TerminalPane(current_pane) => {
                 // Find previous visible pane or go back to task list
                 if current_pane > 0 {
                     if let Some(prev_pane) = (0..current_pane)
                         .rev()
                         .find(|&idx| self.pane_tasks[idx].is_some())
                     {
-                        Focus::MultipleOutput(prev_pane)
+                        Focus::TerminalPane(prev_pane)
                     } else {
                         Focus::TaskList
                     }
@@ -723,15 +1705,18 @@ impl TasksList {
                     Focus::TaskList
                 }
             }
-            Focus::HelpPopup => Focus::TaskList,
+            _ => Focus::TaskList,
         };
+        self.clear_search_on_blurred_pane();
     }
 
     /// Gets the table style based on the current focus state.
     /// Returns a dimmed style when focus is not on the task list.
     fn get_table_style(&self) -> Style {
         match self.focus {
-            Focus::MultipleOutput(_) | Focus::HelpPopup => Style::default().dim(),
+            Focus::TerminalPane(_) | Focus::HelpPopup | Focus::Search | Focus::RunHistory => {
+                Style::default().dim()
+            }
             Focus::TaskList => Style::default(),
         }
     }
@@ -743,7 +1728,7 @@ impl TasksList {
 
     /// Forward key events to the currently focused pane, if any.
     pub fn handle_key_event(&mut self, key: KeyEvent) -> io::Result<()> {
-        if let Focus::MultipleOutput(pane_idx) = self.focus {
+        if let Focus::TerminalPane(pane_idx) = self.focus {
             let terminal_pane_data = &mut self.terminal_pane_data[pane_idx];
             terminal_pane_data.handle_key_event(key)
         } else {
@@ -754,13 +1739,22 @@ impl TasksList {
     /// Returns true if the currently focused pane is in interactive mode.
     pub fn is_interactive_mode(&self) -> bool {
         match self.focus {
-            Focus::MultipleOutput(pane_idx) => self.terminal_pane_data[pane_idx].is_interactive(),
+            Focus::TerminalPane(pane_idx) => self.terminal_pane_data[pane_idx].is_interactive(),
             _ => false,
         }
     }
 
+    /// Sets (or clears, via `None`) the fixed height of the inline viewport. When set, `draw`
+    /// anchors its output to the bottom `height` rows of whatever area it's given instead of
+    /// filling it, and newly-sized PTYs are budgeted against `height` rather than the full
+    /// terminal height.
+    pub fn set_inline_viewport_height(&mut self, height: Option<u16>) {
+        self.inline_viewport_height = height;
+    }
+
     /// Handles window resize events by updating PTY dimensions.
     pub fn handle_resize(&mut self, width: u16, height: u16) -> io::Result<()> {
+        let height = self.inline_viewport_height.unwrap_or(height);
         let output_area = if self.has_visible_panes() {
             let width = (width / 3) * 2; // Two-thirds of width for PTY panes
             Rect::new(0, 0, width, height)
@@ -865,7 +1859,7 @@ impl TasksList {
             if let Some(task) = self.tasks.get_mut(task_idx) {
                 if matches!(task.status, TaskStatus::NotStarted) {
                     // Start the task with current UI dimensions
-                    match task.start_task(&self.command_lookup) {
+                    match task.start_task(&self.command_lookup, self.inline_viewport_height) {
                         Ok(_) => {
                             self.last_task_start = Some(now);
                             self.queued_tasks.remove(0);
@@ -905,40 +1899,39 @@ impl TasksList {
 
     /// Creates header cells for the task list table.
     /// Shows either filter input or task status based on current state.
-    fn get_header_cells(&self, collapsed_mode: bool) -> Vec<Cell> {
-        let should_dim = matches!(self.focus, Focus::MultipleOutput(_));
+    fn get_header_cells(&self, columns: TableColumns) -> Vec<Cell> {
+        let should_dim = matches!(self.focus, Focus::TerminalPane(_));
         let status_style = if should_dim {
             Style::default().fg(Color::DarkGray).dim()
         } else {
             Style::default().fg(Color::DarkGray)
         };
 
-        // Show filter input when in filter mode
-        if self.filter_mode || !self.filter_text.is_empty() {
+        // Show search input/status when in search mode (distinct from the filter below -
+        // search never hides rows, it just ranks and highlights matches)
+        let mut cells = if self.search_mode {
+            let search_text = if self.search_results.matches.is_empty() {
+                format!("Search: {}", self.search_query)
+            } else {
+                format!(
+                    "Search: {} ({}/{} matches, n/N to jump)",
+                    self.search_query,
+                    self.search_results.cursor + 1,
+                    self.search_results.matches.len()
+                )
+            };
+
+            vec![
+                Cell::from("").style(status_style),
+                Cell::from(search_text).style(Style::default().fg(Color::Cyan)),
+            ]
+        } else if self.filter_mode || !self.filter_text.is_empty() {
             let filter_text = format!("Filter: {}", self.filter_text);
-            let filter_style = Style::default().fg(Color::Yellow);
 
-            if collapsed_mode {
-                vec![
-                    Cell::from("").style(status_style),
-                    Cell::from(filter_text).style(filter_style),
-                ]
-            } else {
-                vec![
-                    Cell::from("").style(status_style),
-                    Cell::from(filter_text).style(filter_style),
-                    Cell::from(Line::from("Cache").right_aligned()).style(
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Cell::from(Line::from("Duration").right_aligned()).style(
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                ]
-            }
+            vec![
+                Cell::from("").style(status_style),
+                Cell::from(filter_text).style(Style::default().fg(Color::Yellow)),
+            ]
         } else {
             // Show normal status text
             let (running, remaining) = self.get_task_counts();
@@ -973,34 +1966,33 @@ impl TasksList {
                 } else {
                     format!("Completed {} tasks", completed)
                 }
-            } else if collapsed_mode {
+            } else if columns.is_narrow() {
                 format!("{}/{} remaining...", running, remaining)
             } else {
                 format!("Executing {}/{} remaining tasks...", running, remaining)
             };
 
-            if collapsed_mode {
-                vec![
-                    Cell::from("").style(status_style),
-                    Cell::from(status_text).style(status_style),
-                ]
-            } else {
-                vec![
-                    Cell::from("").style(status_style),
-                    Cell::from(status_text).style(status_style),
-                    Cell::from(Line::from("Cache").right_aligned()).style(
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Cell::from(Line::from("Duration").right_aligned()).style(
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                ]
-            }
+            vec![
+                Cell::from("").style(status_style),
+                Cell::from(status_text).style(status_style),
+            ]
+        };
+
+        let column_header_style = Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD);
+        if columns.cache {
+            cells.push(
+                Cell::from(Line::from("Cache").right_aligned()).style(column_header_style),
+            );
         }
+        if columns.duration {
+            cells.push(
+                Cell::from(Line::from("Duration").right_aligned()).style(column_header_style),
+            );
+        }
+
+        cells
     }
 
     /// Sets whether the component should be displayed in a dimmed state.
@@ -1035,9 +2027,10 @@ impl TasksList {
                         .as_millis(),
                 );
 
-                // Get terminal size
-                let terminal_size = crossterm::terminal::size().unwrap_or((80, 24));
-                let (width, height) = terminal_size;
+                // Get terminal size, capping the height to the inline viewport budget (if any)
+                // so the PTY doesn't size itself for rows that sit outside the anchored region.
+                let (width, full_height) = crossterm::terminal::size().unwrap_or((80, 24));
+                let height = self.inline_viewport_height.unwrap_or(full_height);
 
                 // Calculate dimensions using the same logic as handle_resize
                 let output_width = (width / 3) * 2; // Two-thirds of width for PTY panes
@@ -1056,6 +2049,7 @@ impl TasksList {
     }
 
     pub fn end_tasks(&mut self, task_results: Vec<TaskResult>) {
+        let inline_viewport_height = self.inline_viewport_height;
         for task_result in task_results {
             if let Some(task) = self
                 .tasks
@@ -1068,7 +2062,17 @@ impl TasksList {
                     task.update_output(
                         task_result.terminal_output.unwrap_or_default().as_str(),
                         task_result.status.parse().unwrap(),
+                        inline_viewport_height,
                     );
+                } else if let Ok(status) = task_result.status.parse::<TaskStatus>() {
+                    // A cache hit - the task runner reports the real cache status on the
+                    // result itself rather than via a terminal output diff, so there's no PTY
+                    // completion to derive it from (see `complete_cached_task`, the other path
+                    // that feeds this same cache-status data in).
+                    if let Some(label) = status.cache_label() {
+                        task.cache = label;
+                        task.status = status;
+                    }
                 }
 
                 // TODO: Migrate to the actual data Nx gives us for timings
@@ -1114,15 +2118,27 @@ impl TasksList {
             task.completed_at = Some(now);
             task.duration = "<1ms".to_string();
 
-            task.update_output(output.unwrap_or_default(), status);
+            task.update_output(
+                output.unwrap_or_default(),
+                status,
+                self.inline_viewport_height,
+            );
 
             // TODO: Do we actually need this separate property on the task item? We can probably derive it from the status in draw, legacy of POC
-            task.cache = match status {
-                TaskStatus::LocalCacheKeptExisting => CACHE_STATUS_LOCAL_KEPT_EXISTING.to_string(),
-                TaskStatus::LocalCache => CACHE_STATUS_LOCAL.to_string(),
-                TaskStatus::RemoteCache => CACHE_STATUS_REMOTE.to_string(),
-                _ => unreachable!(),
-            };
+            task.cache = status.cache_label().unwrap_or_else(|| unreachable!());
+
+            // Cached tasks never pass through `update_status`'s tick-driven completion
+            // detection, so record their history here instead.
+            if let Some(record) = TaskHistoryRecord::new(
+                task.name.clone(),
+                task.started_at.unwrap_or(0),
+                task.completed_at.unwrap_or(0),
+                format!("{:?}", task.status),
+                task.cache.clone(),
+                &task.terminal_output,
+            ) {
+                self.history.record(record);
+            }
 
             self.sort_tasks();
         }
@@ -1146,14 +2162,32 @@ impl TasksList {
 
 impl Component for TasksList {
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
-        // Determine if we should use collapsed mode based on viewport width
-        let collapsed_mode = self.has_visible_panes() || area.width < 100;
+        // In inline viewport mode, render into a fixed-height region anchored at the bottom of
+        // `area` so prior shell output and completed-task logs stay in the user's scrollback.
+        let area = if let Some(budget) = self.inline_viewport_height {
+            let height = budget.min(area.height);
+            Rect {
+                y: area.y + (area.height - height),
+                height,
+                ..area
+            }
+        } else {
+            area
+        };
+
+        self.last_box_area = area;
+
+        // Determine if we should use collapsed mode based on viewport width - the inline
+        // viewport is always treated as collapsed since its bounded height leaves no room for
+        // a side-by-side output-pane split.
+        let collapsed_mode =
+            self.has_visible_panes() || area.width < 100 || self.inline_viewport_height.is_some();
 
         // Calculate the width for the task list
         let task_list_width = if self.task_list_hidden {
             0
         } else if collapsed_mode {
-            area.width / 3
+            (area.width as f32 * self.task_list_split_ratio) as u16
         } else {
             area.width
         };
@@ -1183,6 +2217,14 @@ impl Component for TasksList {
 
             let has_short_viewport = task_list_area.height < 12;
 
+            // Collapsed mode always drops the optional columns outright; otherwise let the
+            // builder decide which of Cache/Duration fit in the task list's own width.
+            let table_columns = if collapsed_mode {
+                TableColumns::NONE
+            } else {
+                TableBuilder::columns(task_list_area.width)
+            };
+
             // Create layout for title, table and bottom elements
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
@@ -1222,7 +2264,7 @@ impl Component for TasksList {
                 .split(title_area);
 
             let title_style = if self.is_dimmed
-                || matches!(self.focus, Focus::MultipleOutput(_) | Focus::HelpPopup)
+                || matches!(self.focus, Focus::TerminalPane(_) | Focus::HelpPopup)
             {
                 Style::default().add_modifier(Modifier::DIM)
             } else {
@@ -1237,10 +2279,55 @@ impl Component for TasksList {
             ];
 
             let task_names = self.target_names.clone();
+            // Each target name doubles as a tab: the active tab (or all of them, on the "All"
+            // tab) is highlighted so it's clear which target's tasks are currently shown.
+            let tab_style = |idx: usize| {
+                if self.active_tab == Some(idx) {
+                    title_style.fg(Color::Cyan).add_modifier(Modifier::UNDERLINED)
+                } else {
+                    title_style.fg(Color::Gray)
+                }
+            };
+
+            // Reserve space at the end of the title row for the aggregate progress gauge and
+            // (when there's room and we're not already tight on width) a duration sparkline.
+            const GAUGE_WIDTH: u16 = 18;
+            const SPARKLINE_WIDTH: u16 = 20;
+            let (completed_count, total_count, has_failures) = self.task_progress_summary();
+            let show_gauge = total_count > 0 && title_chunks[1].width > GAUGE_WIDTH + 20;
+            let show_sparkline =
+                show_gauge && !collapsed_mode && title_chunks[1].width > GAUGE_WIDTH + SPARKLINE_WIDTH + 20;
+
+            let mut title_row_constraints = vec![Constraint::Min(0)];
+            if show_sparkline {
+                title_row_constraints.push(Constraint::Length(SPARKLINE_WIDTH));
+            }
+            if show_gauge {
+                title_row_constraints.push(Constraint::Length(GAUGE_WIDTH));
+            }
+            let title_row_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(title_row_constraints)
+                .split(title_chunks[1]);
+
+            let title_text_area = title_row_chunks[0];
+            let mut next_title_row_chunk = 1;
+            let sparkline_area = if show_sparkline {
+                let area = title_row_chunks[next_title_row_chunk];
+                next_title_row_chunk += 1;
+                Some(area)
+            } else {
+                None
+            };
+            let gauge_area = if show_gauge {
+                Some(title_row_chunks[next_title_row_chunk])
+            } else {
+                None
+            };
 
             // Calculate the width of the fixed elements (everything except task names)
             let fixed_width: usize = title_text.iter().map(|s| s.width()).sum();
-            let available_width = title_chunks[1].width as usize;
+            let available_width = title_text_area.width as usize;
 
             // If we're in collapsed mode (output panes showing), we may need to truncate
             if collapsed_mode && !task_names.is_empty() {
@@ -1250,17 +2337,14 @@ impl Component for TasksList {
                 let mut included_names = Vec::new();
 
                 // Add names until we run out of space
-                for name in task_names.iter() {
+                for (idx, name) in task_names.iter().enumerate() {
                     let name_width = name.len() + 1; // +1 for the space
                     if current_width + name_width > space_for_names {
                         // No more space, add ellipsis and break
                         included_names.push(Span::styled("...", title_style.fg(Color::DarkGray)));
                         break;
                     }
-                    included_names.push(Span::styled(
-                        format!(" {}", name),
-                        title_style.fg(Color::Gray),
-                    ));
+                    included_names.push(Span::styled(format!(" {}", name), tab_style(idx)));
                     current_width += name_width;
                 }
 
@@ -1269,7 +2353,8 @@ impl Component for TasksList {
                 // Original behavior for non-collapsed mode
                 let middle_spans: Vec<Span> = task_names
                     .iter()
-                    .map(|s| Span::styled(format!(" {}", s), title_style.fg(Color::Gray)))
+                    .enumerate()
+                    .map(|(idx, s)| Span::styled(format!(" {}", s), tab_style(idx)))
                     .collect();
 
                 title_text.extend(middle_spans);
@@ -1284,7 +2369,40 @@ impl Component for TasksList {
             let paragraph = Paragraph::new(Line::from(title_text)).alignment(Alignment::Left);
 
             // Render the title
-            f.render_widget(paragraph, title_chunks[1]);
+            f.render_widget(paragraph, title_text_area);
+
+            // Plots each task's relative duration so slow tasks stand out at a glance; tasks
+            // with no duration yet (not started, or continuous) are skipped rather than
+            // rendered as zero-height bars.
+            if let Some(area) = sparkline_area {
+                let durations: Vec<u64> = self
+                    .tasks
+                    .iter()
+                    .filter_map(|t| Self::parse_duration_ms(&t.duration))
+                    .collect();
+                if !durations.is_empty() {
+                    let sparkline = Sparkline::default()
+                        .data(&durations)
+                        .style(title_style.fg(Color::Cyan));
+                    f.render_widget(sparkline, area);
+                }
+            }
+
+            // Aggregate completed-vs-total progress across every task, colored red the moment
+            // any task has failed so a failing run is visible without scanning the table.
+            if let Some(area) = gauge_area {
+                let ratio = if total_count > 0 {
+                    completed_count as f64 / total_count as f64
+                } else {
+                    0.0
+                };
+                let gauge_color = if has_failures { Color::Red } else { Color::Green };
+                let gauge = Gauge::default()
+                    .gauge_style(title_style.fg(gauge_color))
+                    .ratio(ratio.clamp(0.0, 1.0))
+                    .label(format!("{completed_count}/{total_count}"));
+                f.render_widget(gauge, area);
+            }
 
             // Reserve space for pagination and borders
             self.recalculate_pages(table_area.height.saturating_sub(6));
@@ -1294,7 +2412,7 @@ impl Component for TasksList {
             let normal_style = Style::default();
 
             // Get header cells using the new method
-            let header_cells = self.get_header_cells(collapsed_mode);
+            let header_cells = self.get_header_cells(table_columns);
 
             let header = Row::new(header_cells)
                 .style(normal_style)
@@ -1308,72 +2426,74 @@ impl Component for TasksList {
             // Add filter summary row if filtering or there are filtered tasks
             let hidden_tasks = self.tasks.len() - self.filtered_names.len();
             if self.filter_mode || !self.filter_text.is_empty() {
-                let filter_cells = if collapsed_mode {
-                    vec![
-                        Cell::from(""),
-                        Cell::from(if hidden_tasks > 0 {
-                            if self.filter_persisted {
-                                format!(
-                                    "{} tasks filtered out. Press / to edit, <esc> to clear",
-                                    hidden_tasks
-                                )
-                            } else {
-                                format!(
-                                    "{} tasks filtered out. Press / to persist, <esc> to clear",
-                                    hidden_tasks
-                                )
-                            }
-                        } else if self.filter_persisted {
-                            "Press / to edit filter".to_string()
-                        } else {
-                            "Press <esc> to clear filter".to_string()
-                        })
-                        .style(Style::default().fg(Color::Yellow)),
-                    ]
-                } else {
-                    vec![
-                        Cell::from(""),
-                        Cell::from(if hidden_tasks > 0 {
-                            if self.filter_persisted {
-                                format!(
-                                    "{} tasks filtered out. Press / to edit, <esc> to clear",
-                                    hidden_tasks
-                                )
-                            } else {
-                                format!(
-                                    "{} tasks filtered out. Press / to persist, <esc> to clear",
-                                    hidden_tasks
-                                )
-                            }
-                        } else if self.filter_persisted {
-                            "Press / to edit filter".to_string()
+                let mut filter_cells = vec![
+                    Cell::from(""),
+                    Cell::from(if hidden_tasks > 0 {
+                        if self.filter_persisted {
+                            format!(
+                                "{} tasks filtered out. Press / to edit, <esc> to clear",
+                                hidden_tasks
+                            )
                         } else {
-                            "Press <esc> to clear filter".to_string()
-                        })
-                        .style(Style::default().fg(Color::Yellow)),
-                        Cell::from(""),
-                        Cell::from(""),
-                    ]
-                };
+                            format!(
+                                "{} tasks filtered out. Press / to persist, <esc> to clear",
+                                hidden_tasks
+                            )
+                        }
+                    } else if self.filter_persisted {
+                        "Press / to edit filter".to_string()
+                    } else {
+                        "Press <esc> to clear filter".to_string()
+                    })
+                    .style(Style::default().fg(Color::Yellow)),
+                ];
+                filter_cells.extend(Self::empty_column_cells(table_columns));
                 all_rows.push(Row::new(filter_cells).height(1));
 
                 // Add empty row after filter summary
-                let empty_cells = if collapsed_mode {
-                    vec![Cell::from(""), Cell::from("")]
-                } else {
-                    vec![
-                        Cell::from(""),
-                        Cell::from(""),
-                        Cell::from(""),
-                        Cell::from(""),
-                    ]
-                };
+                let mut empty_cells = vec![Cell::from(""), Cell::from("")];
+                empty_cells.extend(Self::empty_column_cells(table_columns));
                 all_rows.push(Row::new(empty_cells).height(1));
             }
 
             // Add task rows
             all_rows.extend(visible_entries.iter().map(|entry| {
                 if let Some(task_name) = entry {
+                    if Self::is_group_header(task_name) {
+                        let project = Self::group_header_project(task_name);
+                        let is_selected = self.selection_manager.is_selected(&task_name);
+                        let (status, completed, total) = self.group_summary(project);
+                        let is_collapsed = self.collapsed_groups.contains(project);
+                        let status_color = match status {
+                            TaskStatus::Success => Color::Green,
+                            TaskStatus::Failure => Color::Red,
+                            TaskStatus::InProgress => Color::LightCyan,
+                            TaskStatus::NotStarted => Color::DarkGray,
+                            _ => Color::Green,
+                        };
+
+                        let caret = Span::raw(if is_selected { " > " } else { "   " });
+                        let disclosure = if is_collapsed { " ▶" } else { " ▼" };
+                        let status_cell = Cell::from(Line::from(vec![
+                            caret,
+                            Span::styled(disclosure, Style::default().fg(status_color)),
+                        ]));
+
+                        let name_cell = Cell::from(Line::from(vec![Span::styled(
+                            format!("{} ({}/{})", project, completed, total),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        )]));
+
+                        let mut row_cells = vec![status_cell, name_cell];
+                        row_cells.extend(Self::empty_column_cells(table_columns));
+
+                        return Row::new(row_cells).height(1).style(if is_selected {
+                            selected_style
+                        } else {
+                            normal_style
+                        });
+                    }
+
                     // Find the task in the filtered list
                     if let Some(task) = self.tasks.iter().find(|t| &t.name == task_name) {
                         let is_selected = self.selection_manager.is_selected(&task_name);
@@ -1463,6 +2583,41 @@ impl Component for TasksList {
                                     ),
                                 ]);
                                 Cell::from(line)
+                            } else if let Some(indices) = self.search_match_indices(&task_name) {
+                                let spans: Vec<Span> = task_name
+                                    .chars()
+                                    .enumerate()
+                                    .map(|(i, ch)| {
+                                        if indices.contains(&i) {
+                                            Span::styled(
+                                                ch.to_string(),
+                                                Style::default()
+                                                    .fg(Color::Black)
+                                                    .bg(Color::Yellow),
+                                            )
+                                        } else {
+                                            Span::raw(ch.to_string())
+                                        }
+                                    })
+                                    .collect();
+                                Cell::from(Line::from(spans))
+                            } else if let Some(indices) = self.filter_match_indices(&task_name) {
+                                let spans: Vec<Span> = task_name
+                                    .chars()
+                                    .enumerate()
+                                    .map(|(i, ch)| {
+                                        if indices.contains(&i) {
+                                            Span::styled(
+                                                ch.to_string(),
+                                                Style::default()
+                                                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                                            )
+                                        } else {
+                                            Span::raw(ch.to_string())
+                                        }
+                                    })
+                                    .collect();
+                                Cell::from(Line::from(spans))
                             } else {
                                 Cell::from(task_name.clone())
                             }
@@ -1470,7 +2625,7 @@ impl Component for TasksList {
 
                         let mut row_cells = vec![status_cell, name];
 
-                        if !collapsed_mode {
+                        if table_columns.cache {
                             row_cells.push(Cell::from(
                                 Line::from(match task.cache.as_str() {
                                     "..." | "-" => {
@@ -1483,6 +2638,8 @@ impl Component for TasksList {
                                 })
                                 .right_aligned(),
                             ));
+                        }
+                        if table_columns.duration {
                             row_cells.push(Cell::from(
                                 Line::from(match task.duration.as_str() {
                                     "" | "Continuous" => vec![Span::styled(
@@ -1506,30 +2663,13 @@ impl Component for TasksList {
                     }
                 } else {
                     // Handle separator rows
-                    let empty_cells = if collapsed_mode {
-                        vec![Cell::from(""), Cell::from("")]
-                    } else {
-                        vec![
-                            Cell::from(""),
-                            Cell::from(""),
-                            Cell::from(""),
-                            Cell::from(""),
-                        ]
-                    };
+                    let mut empty_cells = vec![Cell::from(""), Cell::from("")];
+                    empty_cells.extend(Self::empty_column_cells(table_columns));
                     Row::new(empty_cells).height(1)
                 }
             }));
 
-            let constraints = if collapsed_mode {
-                vec![Constraint::Length(8), Constraint::Fill(1)]
-            } else {
-                vec![
-                    Constraint::Length(8),  // Status icon
-                    Constraint::Fill(1),    // Task name
-                    Constraint::Length(30), // Cache status (increased width)
-                    Constraint::Length(15), // Duration (increased width)
-                ]
-            };
+            let constraints = TableBuilder::constraints(table_columns);
 
             let t = Table::new(all_rows, &constraints)
                 .header(header)
@@ -1570,7 +2710,7 @@ impl Component for TasksList {
             };
 
             // Determine if bottom bar elements should be dimmed
-            let should_dim = matches!(self.focus, Focus::MultipleOutput(_));
+            let should_dim = matches!(self.focus, Focus::TerminalPane(_));
 
             // Pagination (always shown)
             let total_pages = self.selection_manager.total_pages();
@@ -1578,8 +2718,10 @@ impl Component for TasksList {
             let pagination = Pagination::new(current_page, total_pages);
             pagination.render(f, bottom_layout[0], should_dim);
 
-            // Help text
-            let help_text = HelpText::new(collapsed_mode, should_dim, needs_vertical_bottom_layout);
+            // Help text - the hint set itself is derived from focus/filter/selection state, and
+            // width-fitting (shrinking then dropping the lowest-priority hints, with a trailing
+            // ellipsis if anything was hidden) happens against whatever Rect it's rendered into.
+            let help_text = HelpText::new(self.focus, self.filter_mode, self.selected_is_group_header());
             if !self.is_dimmed {
                 // If dealing with a constrained viewport, we need to align horizontally
                 if needs_vertical_bottom_layout {
@@ -1592,10 +2734,10 @@ impl Component for TasksList {
                         ])
                         .split(bottom_layout[1])[1];
 
-                    help_text.render(f, help_text_area);
+                    help_text.render(f, help_text_area, should_dim);
                 } else {
                     // Original rendering without padding
-                    help_text.render(f, bottom_layout[1]);
+                    help_text.render(f, bottom_layout[1], should_dim);
                 }
             }
         }
@@ -1611,68 +2753,12 @@ impl Component for TasksList {
 
             let num_active_panes = self.pane_tasks.iter().filter(|t| t.is_some()).count();
 
-            match num_active_panes {
-                0 => (), // No panes to render
-                1 => {
-                    if self.pane_tasks[1].is_some() {
-                        let output_chunks = Layout::default()
-                            .direction(Direction::Horizontal)
-                            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                            .spacing(2)
-                            .split(output_area);
-
-                        // Render placeholder for pane 1
-                        let placeholder = Paragraph::new("Press 1 on a task to show it here")
-                            .block(
-                                Block::default()
-                                    .title("  Output 1  ")
-                                    .borders(Borders::ALL)
-                                    .border_style(Style::default().fg(Color::DarkGray)),
-                            )
-                            .style(Style::default().fg(Color::DarkGray))
-                            .alignment(Alignment::Center);
-
-                        f.render_widget(placeholder, output_chunks[0]);
+            if num_active_panes > 0 {
+                let chunks =
+                    Self::pane_layout(output_area, self.pane_tasks.len(), self.pane_split_ratio);
 
-                        // Get task data before rendering
-                        if let Some(task_name) = &self.pane_tasks[1] {
-                            if let Some(task) = self.tasks.iter_mut().find(|t| t.name == *task_name)
-                            {
-                                let mut terminal_pane_data = &mut self.terminal_pane_data[1];
-                                terminal_pane_data.status = task.status;
-                                terminal_pane_data.is_continuous = task.continuous;
-
-                                if let Some(pty) = &mut task.pty {
-                                    terminal_pane_data.pty = Some(pty.clone());
-                                }
-
-                                let is_focused = match self.focus {
-                                    Focus::MultipleOutput(focused_pane_idx) => {
-                                        1 == focused_pane_idx
-                                    }
-                                    _ => false,
-                                };
-                                let mut state = TerminalPaneState::default();
-
-                                let terminal_pane = TerminalPane::new()
-                                    .task_name(task.name.clone())
-                                    .pty_data(&mut terminal_pane_data)
-                                    .focused(is_focused)
-                                    .continuous(task.continuous);
-
-                                f.render_stateful_widget(
-                                    terminal_pane,
-                                    output_chunks[1],
-                                    &mut state,
-                                );
-                            }
-                        }
-                    } else if let Some((pane_idx, Some(task_name))) = self
-                        .pane_tasks
-                        .iter()
-                        .enumerate()
-                        .find(|(_, t)| t.is_some())
-                    {
+                for (pane_idx, chunk) in chunks.iter().enumerate() {
+                    if let Some(task_name) = &self.pane_tasks[pane_idx] {
                         if let Some(task) = self.tasks.iter_mut().find(|t| t.name == *task_name) {
                             let mut terminal_pane_data = &mut self.terminal_pane_data[pane_idx];
                             terminal_pane_data.status = task.status;
@@ -1683,7 +2769,9 @@ impl Component for TasksList {
                             }
 
                             let is_focused = match self.focus {
-                                Focus::MultipleOutput(focused_pane_idx) => 0 == focused_pane_idx,
+                                Focus::TerminalPane(focused_pane_idx) => {
+                                    pane_idx == focused_pane_idx
+                                }
                                 _ => false,
                             };
                             let mut state = TerminalPaneState::default();
@@ -1694,59 +2782,23 @@ impl Component for TasksList {
                                 .focused(is_focused)
                                 .continuous(task.continuous);
 
-                            f.render_stateful_widget(terminal_pane, output_area, &mut state);
+                            f.render_stateful_widget(terminal_pane, *chunk, &mut state);
                         }
-                    }
-                }
-                _ => {
-                    let output_chunks = Layout::default()
-                        .direction(Direction::Horizontal)
-                        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                        .spacing(2)
-                        .split(output_area);
-
-                    for (pane_idx, chunk) in output_chunks.iter().enumerate() {
-                        if let Some(task_name) = &self.pane_tasks[pane_idx] {
-                            if let Some(task) = self.tasks.iter_mut().find(|t| t.name == *task_name)
-                            {
-                                let mut terminal_pane_data = &mut self.terminal_pane_data[pane_idx];
-                                terminal_pane_data.status = task.status;
-                                terminal_pane_data.is_continuous = task.continuous;
-
-                                if let Some(pty) = &mut task.pty {
-                                    terminal_pane_data.pty = Some(pty.clone());
-                                }
-
-                                let is_focused = match self.focus {
-                                    Focus::MultipleOutput(focused_pane_idx) => {
-                                        pane_idx == focused_pane_idx
-                                    }
-                                    _ => false,
-                                };
-                                let mut state = TerminalPaneState::default();
-
-                                let terminal_pane = TerminalPane::new()
-                                    .task_name(task.name.clone())
-                                    .pty_data(&mut terminal_pane_data)
-                                    .focused(is_focused)
-                                    .continuous(task.continuous);
+                    } else {
+                        let placeholder = Paragraph::new(format!(
+                            "Press {} on a task to show it here",
+                            pane_idx + 1
+                        ))
+                        .block(
+                            Block::default()
+                                .title(format!("Output {}", pane_idx + 1))
+                                .borders(Borders::ALL)
+                                .border_style(Style::default().fg(Color::DarkGray)),
+                        )
+                        .style(Style::default().fg(Color::DarkGray))
+                        .alignment(Alignment::Center);
 
-                                f.render_stateful_widget(terminal_pane, *chunk, &mut state);
-                            }
-                        } else {
-                            let placeholder =
-                                Paragraph::new("Press 1 or 2 on a task to show it here")
-                                    .block(
-                                        Block::default()
-                                            .title(format!("Output {}", pane_idx + 1))
-                                            .borders(Borders::ALL)
-                                            .border_style(Style::default().fg(Color::DarkGray)),
-                                    )
-                                    .style(Style::default().fg(Color::DarkGray))
-                                    .alignment(Alignment::Center);
-
-                            f.render_widget(placeholder, *chunk);
-                        }
+                        f.render_widget(placeholder, *chunk);
                     }
                 }
             }
@@ -1781,6 +2833,23 @@ impl Component for TasksList {
                     .zip(old_statuses.iter())
                     .any(|(task, old_status)| &task.status != old_status);
 
+                // Record history for tasks that just reached a terminal status, now that
+                // `update_status` has finalized their timings/cache/status together.
+                for (task, old_status) in self.tasks.iter().zip(old_statuses.iter()) {
+                    if &task.status != old_status && task.status.is_terminal() {
+                        if let Some(record) = TaskHistoryRecord::new(
+                            task.name.clone(),
+                            task.started_at.unwrap_or(0),
+                            task.completed_at.unwrap_or(0),
+                            format!("{:?}", task.status),
+                            task.cache.clone(),
+                            &task.terminal_output,
+                        ) {
+                            self.history.record(record);
+                        }
+                    }
+                }
+
                 if status_changed {
                     self.sort_tasks();
                 }
@@ -1847,16 +2916,96 @@ impl Default for TasksList {
             scroll_offset: 0,
             scrollbar_state: ScrollbarState::default(),
             content_height: 0,
-            pane_tasks: [None, None],
+            pane_tasks: vec![None; DEFAULT_PANE_COUNT],
             focused_pane: None,
             last_task_start: None,
             queued_tasks: Vec::new(),
             is_dimmed: false,
             spacebar_mode: false,
-            terminal_pane_data: [TerminalPaneData::default(), TerminalPaneData::default()],
+            terminal_pane_data: (0..DEFAULT_PANE_COUNT)
+                .map(|_| TerminalPaneData::default())
+                .collect(),
             command_lookup: CommandLookup::default(),
             target_names: Vec::new(),
             task_list_hidden: false,
+            pre_filter_selection: None,
+            filter_match_indices: std::collections::HashMap::new(),
+            active_tab: None,
+            search_mode: false,
+            search_query: String::new(),
+            search_results: SearchResults::default(),
+            pre_search_selection: None,
+            grouped_view: false,
+            collapsed_groups: std::collections::HashSet::new(),
+            inline_viewport_height: None,
+            history: RunHistoryStore::default(),
+            task_list_split_ratio: Self::DEFAULT_TASK_LIST_SPLIT_RATIO,
+            pane_split_ratio: Self::DEFAULT_PANE_SPLIT_RATIO,
+            tab_state: std::collections::HashMap::new(),
+            color_mode: ColorMode::Auto,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_file_name_replaces_colons_for_a_flat_filesystem_path() {
+        assert_eq!(TaskItem::log_file_name("my-app:build"), "my-app-build.txt");
+    }
+
+    #[test]
+    fn get_cached_output_prefers_in_memory_output_over_disk() {
+        let mut task = TaskItem::new("my-app:build".to_string(), false);
+        task.terminal_output = "fresh in-memory output".to_string();
+        assert_eq!(
+            task.get_cached_output(),
+            Some(b"fresh in-memory output".to_vec())
+        );
+    }
+
+    #[test]
+    fn clamp_split_ratio_passes_through_a_ratio_that_keeps_both_sides_above_the_minimum() {
+        assert_eq!(TasksList::clamp_split_ratio(0.5, 100), 0.5);
+    }
+
+    #[test]
+    fn clamp_split_ratio_snaps_back_before_either_side_would_collapse() {
+        // At width 100, MIN_SPLIT_DIMENSION (10) is a 0.1 fraction of the total.
+        assert_eq!(TasksList::clamp_split_ratio(0.02, 100), 0.1);
+        assert_eq!(TasksList::clamp_split_ratio(0.98, 100), 0.9);
+    }
+
+    #[test]
+    fn clamp_split_ratio_falls_back_to_the_full_unit_range_when_width_is_zero() {
+        assert_eq!(TasksList::clamp_split_ratio(1.5, 0), 1.0);
+        assert_eq!(TasksList::clamp_split_ratio(-0.5, 0), 0.0);
+    }
+
+    #[test]
+    fn fuzzy_match_returns_none_when_query_is_not_a_subsequence() {
+        assert_eq!(TasksList::fuzzy_match("my-app:build", "xyz"), None);
+    }
+
+    #[test]
+    fn fuzzy_match_matches_case_insensitively_and_reports_indices() {
+        let (_, indices) = TasksList::fuzzy_match("my-app:build", "MAB").unwrap();
+        assert_eq!(indices, vec![0, 3, 7]);
+    }
+
+    #[test]
+    fn fuzzy_match_scores_a_contiguous_match_higher_than_a_scattered_one() {
+        // Neither query touches index 0 or a '-'/':'/'/'/'_' boundary, so the only scoring
+        // difference between them is the contiguous-match bonus vs. the scattered-gap penalty.
+        let (contiguous_score, _) = TasksList::fuzzy_match("abcdefgh", "cd").unwrap();
+        let (scattered_score, _) = TasksList::fuzzy_match("abcdefgh", "ce").unwrap();
+        assert!(contiguous_score > scattered_score);
+    }
+
+    #[test]
+    fn fuzzy_match_treats_an_empty_query_as_a_zero_score_match() {
+        assert_eq!(TasksList::fuzzy_match("my-app:build", ""), Some((0, Vec::new())));
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Where a declarative layout preset is read from, relative to the workspace root - lets a
+/// developer check a common "dev server in pane 1, watcher in pane 2" setup into version
+/// control instead of re-pinning tasks by hand every session.
+const LAYOUT_PRESET_PATH: &str = "nx-tui.json";
+
+/// One task's desired output-pane assignment from a layout preset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaneAssignment {
+    pub task_name: String,
+    pub pane_index: usize,
+    #[serde(default)]
+    pub continuous: bool,
+}
+
+/// A saved layout - which tasks should be auto-pinned into which output panes, and what the
+/// task list should be filtered to, restored in one shot on launch or on demand via a keybind.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LayoutPreset {
+    pub panes: Vec<PaneAssignment>,
+    #[serde(default)]
+    pub default_filter: Option<String>,
+}
+
+impl LayoutPreset {
+    /// Loads the preset from `nx-tui.json` in the current working directory, if present.
+    /// Returns `None` on any I/O or parse error so a missing/malformed config just means no
+    /// preset is applied, rather than a startup failure.
+    pub fn load() -> Option<Self> {
+        let content = fs::read_to_string(LAYOUT_PRESET_PATH).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Keeps only the pane assignments that name a task actually present in this run, so a
+    /// stale preset (renamed/removed tasks) degrades to pinning whatever still matches instead
+    /// of being rejected outright.
+    pub fn valid_assignments<'a>(
+        &'a self,
+        known_task_names: &'a [String],
+    ) -> impl Iterator<Item = &'a PaneAssignment> {
+        self.panes
+            .iter()
+            .filter(|assignment| known_task_names.iter().any(|name| name == &assignment.task_name))
+    }
+}
@@ -1,12 +1,18 @@
 use crate::native::tui::action::Action;
 use color_eyre::eyre::Result;
 use log::debug;
+use notify::RecursiveMode;
+use notify_debouncer_full::{new_debouncer, DebounceEventResult, Debouncer, FileIdMap, RecommendedCache};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tokio::{sync::mpsc, task::JoinHandle, time};
+use tokio::{
+    sync::{mpsc, watch},
+    task::JoinHandle,
+    time,
+};
 
 /// Simple log entry with just content
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,27 +20,121 @@ pub struct LogEntry {
     pub content: String,
 }
 
-/// Log watcher that monitors a JSON file for changes
+/// How long a burst of file events is allowed to settle before we read the file.
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(75);
+
+/// How many times a parse failure is retried (with backoff) before the file is given up on.
+const MAX_PARSE_RETRIES: u32 = 3;
+
+/// Base delay for the backoff between parse retries, multiplied by the attempt number.
+const PARSE_RETRY_BASE_DELAY: Duration = Duration::from_millis(20);
+
+/// Selects how the log file is expected to be written, and therefore how it's consumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// A single JSON document is written, read in full, and deleted on each update.
+    Snapshot,
+    /// The producer appends newline-delimited JSON entries. We track a byte offset and only
+    /// read newly-appended, complete lines - the file is never deleted or truncated.
+    AppendOnlyNdjson,
+}
+
+/// Selects how the log file is observed for changes.
+#[derive(Debug, Clone)]
+pub enum Watcher {
+    /// Subscribe to filesystem create/modify notifications for the target file, debounced
+    /// over `debounce` so a burst of writes only triggers a single read.
+    Native { debounce: Duration },
+    /// Poll the file on a fixed interval, for environments where native notifications
+    /// aren't available or reliable (e.g. some networked/virtualized filesystems).
+    Poll(Duration),
+}
+
+impl Default for Watcher {
+    fn default() -> Self {
+        Watcher::Native {
+            debounce: DEFAULT_DEBOUNCE,
+        }
+    }
+}
+
+/// A lazily-resolved, awaitable signal that the watcher backend has finished starting up -
+/// modeled on the project graph's `OptionalWatch` handle, so callers can kick off watcher
+/// construction and keep going without blocking on `start_watching` up front.
+#[derive(Clone)]
+pub struct OptionalWatch {
+    rx: watch::Receiver<bool>,
+}
+
+impl OptionalWatch {
+    fn new(rx: watch::Receiver<bool>) -> Self {
+        Self { rx }
+    }
+
+    /// Resolves once the watcher has started, or immediately if it already has.
+    pub async fn ready(&mut self) {
+        if *self.rx.borrow() {
+            return;
+        }
+        // `changed` only errors if the sender (the `LogWatcher`) was dropped before starting.
+        let _ = self.rx.changed().await;
+    }
+
+    /// Non-blocking check of whether the watcher has started yet.
+    pub fn is_ready(&self) -> bool {
+        *self.rx.borrow()
+    }
+}
+
+/// Handle to whichever backend is currently running, so it can be torn down cleanly.
+enum WatcherHandle {
+    Poll(JoinHandle<()>, mpsc::Sender<()>),
+    Native(Debouncer<notify::RecommendedWatcher, RecommendedCache>),
+}
+
+/// Log watcher that monitors a JSON file for changes, using either native filesystem
+/// notifications or interval polling depending on the configured `Watcher` backend.
 pub struct LogWatcher {
     entries: Arc<Mutex<Vec<LogEntry>>>,
     path: PathBuf,
-    watcher_handle: Option<JoinHandle<()>>,
-    _shutdown_tx: Option<mpsc::Sender<()>>,
+    backend: Watcher,
+    format: LogFormat,
+    handle: Option<WatcherHandle>,
     action_tx: Option<mpsc::UnboundedSender<Action>>,
+    // Last successfully-read file content, used to skip re-parsing unchanged content.
+    // Only used for `LogFormat::Snapshot`.
+    last_content: Arc<Mutex<Option<String>>>,
+    // Byte offset up to which the file has already been consumed. Only used for
+    // `LogFormat::AppendOnlyNdjson`.
+    offset: Arc<Mutex<u64>>,
+    ready_tx: watch::Sender<bool>,
 }
 
 impl LogWatcher {
-    /// Create a new log watcher for the specified path
-    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+    /// Create a new log watcher for the specified path, using the given backend and format.
+    /// Construction is cheap and does not start watching - call `start_watching` (and await
+    /// the handle returned by `watch_ready` if you need to know when it's live).
+    pub fn new<P: AsRef<Path>>(path: P, backend: Watcher, format: LogFormat) -> Self {
+        let (ready_tx, _ready_rx) = watch::channel(false);
         Self {
             entries: Arc::new(Mutex::new(Vec::new())),
             path: path.as_ref().to_path_buf(),
-            watcher_handle: None,
-            _shutdown_tx: None,
+            backend,
+            format,
+            handle: None,
             action_tx: None,
+            last_content: Arc::new(Mutex::new(None)),
+            offset: Arc::new(Mutex::new(0)),
+            ready_tx,
         }
     }
 
+    /// Returns a handle that resolves once the watcher has started, without needing to hold a
+    /// `&LogWatcher` borrow for as long as the wait takes.
+    pub fn watch_ready(&self) -> OptionalWatch {
+        OptionalWatch::new(self.ready_tx.subscribe())
+    }
+
     /// Set the action sender for the log watcher
     pub fn set_action_sender(&mut self, tx: mpsc::UnboundedSender<Action>) {
         self.action_tx = Some(tx);
@@ -46,85 +146,125 @@ impl LogWatcher {
         guard.clone()
     }
 
-    /// Start watching the log file
+    /// Start watching the log file using the configured backend
     pub fn start_watching(&mut self) -> Result<()> {
         // Only start if we're not already watching
-        if self.watcher_handle.is_some() {
+        if self.handle.is_some() {
             return Ok(());
         }
 
-        // Don't process initial content - only react to changes
-        // Record the path for watching
+        let result = match self.backend.clone() {
+            Watcher::Poll(interval) => self.start_polling(interval),
+            Watcher::Native { debounce } => self.start_native(debounce),
+        };
+
+        if result.is_ok() {
+            // Wake up anyone awaiting `watch_ready`. `send_replace` stores the value even with
+            // no live receiver, so a handle subscribed later still sees the watcher as started.
+            self.ready_tx.send_replace(true);
+        }
+
+        result
+    }
+
+    fn start_polling(&mut self, interval: Duration) -> Result<()> {
         let path = self.path.clone();
         let action_tx = self.action_tx.clone();
+        let last_content = self.last_content.clone();
+        let offset = self.offset.clone();
+        let format = self.format;
 
         // Create a channel to signal shutdown
         let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
-        self._shutdown_tx = Some(shutdown_tx);
 
-        // Start watching in a background task
-        let handle = tokio::spawn(async move {
-            let mut interval = time::interval(Duration::from_millis(1000));
+        let join_handle = tokio::spawn(async move {
+            let mut ticker = time::interval(interval);
 
             loop {
                 tokio::select! {
-                    _ = interval.tick() => {
-                        // Check if file exists
+                    _ = ticker.tick() => {
                         if path.exists() {
-                            // Read the file content
-                            match fs::read_to_string(&path) {
-                                Ok(content) => {
-                                    // Parse the JSON content
-                                    match serde_json::from_str::<LogEntry>(&content) {
-                                        Ok(entry) => {
-                                            // Send the content to the app
-                                            if let Some(tx) = &action_tx {
-                                                let _ = tx.send(Action::LogFileUpdated(entry.content));
-                                            }
-
-                                            // Delete the file after consuming it
-                                            if let Err(e) = fs::remove_file(&path) {
-                                                debug!("Error deleting log file: {}", e);
-                                            }
-                                        }
-                                        Err(e) => {
-                                            debug!("Error parsing log entry: {}", e);
-
-                                            // Delete the file even if we couldn't parse it
-                                            if let Err(e) = fs::remove_file(&path) {
-                                                debug!("Error deleting log file: {}", e);
-                                            }
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("Error reading log file: {}", e);
-                                }
-                            }
+                            consume_log_file(
+                                path.clone(),
+                                action_tx.clone(),
+                                format,
+                                last_content.clone(),
+                                offset.clone(),
+                            );
                         }
                     }
                     _ = shutdown_rx.recv() => {
-                        // Shutdown signal received
                         break;
                     }
                 }
             }
         });
 
-        self.watcher_handle = Some(handle);
+        self.handle = Some(WatcherHandle::Poll(join_handle, shutdown_tx));
+        Ok(())
+    }
+
+    fn start_native(&mut self, debounce: Duration) -> Result<()> {
+        let path = self.path.clone();
+        let parent_dir = path.parent().unwrap_or(Path::new(".")).to_path_buf();
+        let action_tx = self.action_tx.clone();
+        let last_content = self.last_content.clone();
+        let offset = self.offset.clone();
+        let format = self.format;
+
+        // Coalesce a burst of create/modify events for the target path into a single read,
+        // and track files by inode/id so renames onto the target path are still picked up.
+        let mut debouncer = new_debouncer(
+            debounce,
+            Some(FileIdMap::new()),
+            move |result: DebounceEventResult| {
+                let events = match result {
+                    Ok(events) => events,
+                    Err(errors) => {
+                        for error in errors {
+                            debug!("Log watcher error: {}", error);
+                        }
+                        return;
+                    }
+                };
+
+                let is_relevant_update = events.iter().any(|event| {
+                    event.paths.iter().any(|event_path| event_path == &path)
+                        && matches!(
+                            event.kind,
+                            notify::EventKind::Create(_) | notify::EventKind::Modify(_)
+                        )
+                });
+
+                if is_relevant_update && path.exists() {
+                    consume_log_file(
+                        path.clone(),
+                        action_tx.clone(),
+                        format,
+                        last_content.clone(),
+                        offset.clone(),
+                    );
+                }
+            },
+        )?;
+
+        debouncer.watch(&parent_dir, RecursiveMode::NonRecursive)?;
+
+        self.handle = Some(WatcherHandle::Native(debouncer));
         Ok(())
     }
 
     /// Stop watching the log file
     pub fn stop_watching(&mut self) {
-        if let Some(handle) = self.watcher_handle.take() {
-            // Signal the background task to stop
-            if let Some(tx) = self._shutdown_tx.take() {
-                let _ = tx.try_send(());
+        self.ready_tx.send_replace(false);
+        match self.handle.take() {
+            Some(WatcherHandle::Poll(join_handle, shutdown_tx)) => {
+                let _ = shutdown_tx.try_send(());
+                join_handle.abort();
             }
-
-            // Abort the task
-            handle.abort();
+            // Dropping the debouncer stops its background watch thread.
+            Some(WatcherHandle::Native(_debouncer)) => {}
+            None => {}
         }
     }
 }
@@ -134,3 +274,189 @@ impl Drop for LogWatcher {
         self.stop_watching();
     }
 }
+
+/// Dispatches to the consumer matching the configured `LogFormat`.
+fn consume_log_file(
+    path: PathBuf,
+    action_tx: Option<mpsc::UnboundedSender<Action>>,
+    format: LogFormat,
+    last_content: Arc<Mutex<Option<String>>>,
+    offset: Arc<Mutex<u64>>,
+) {
+    match format {
+        LogFormat::Snapshot => consume_snapshot(path, action_tx, last_content),
+        LogFormat::AppendOnlyNdjson => consume_ndjson_appended(path, action_tx, offset),
+    }
+}
+
+/// Reads the target log file, skipping it if the content hasn't changed since the last read.
+/// Parses the remaining content, retrying a bounded number of times with backoff if it looks
+/// like a partial write, and deletes the file once it's been consumed (or given up on).
+fn consume_snapshot(
+    path: PathBuf,
+    action_tx: Option<mpsc::UnboundedSender<Action>>,
+    last_content: Arc<Mutex<Option<String>>>,
+) {
+    tokio::spawn(async move {
+        let mut attempt = 0;
+
+        loop {
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    debug!("Error reading log file: {}", e);
+                    return;
+                }
+            };
+
+            {
+                let mut guard = last_content.lock().unwrap();
+                if guard.as_deref() == Some(content.as_str()) {
+                    // Content hasn't changed since the last successful read - nothing to do.
+                    return;
+                }
+                *guard = Some(content.clone());
+            }
+
+            match serde_json::from_str::<LogEntry>(&content) {
+                Ok(entry) => {
+                    if let Some(tx) = &action_tx {
+                        let _ = tx.send(Action::LogFileUpdated(entry.content));
+                    }
+
+                    if let Err(e) = fs::remove_file(&path) {
+                        debug!("Error deleting log file: {}", e);
+                    }
+                    return;
+                }
+                Err(e) => {
+                    attempt += 1;
+                    if attempt > MAX_PARSE_RETRIES {
+                        debug!(
+                            "Giving up parsing log entry after {} retries: {}",
+                            attempt - 1,
+                            e
+                        );
+                        if let Err(e) = fs::remove_file(&path) {
+                            debug!("Error deleting log file: {}", e);
+                        }
+                        return;
+                    }
+
+                    // Likely a partial write (the producer hadn't finished flushing the JSON
+                    // document) - wait and retry rather than discarding the entry immediately.
+                    debug!(
+                        "Error parsing log entry, retrying ({}/{}): {}",
+                        attempt, MAX_PARSE_RETRIES, e
+                    );
+                    time::sleep(PARSE_RETRY_BASE_DELAY * attempt).await;
+                }
+            }
+        }
+    });
+}
+
+/// Decides where to resume reading an append-only log file from. If the file is shorter than
+/// the previously-stored offset, it was truncated or rotated out from under us, so there's
+/// nothing meaningful to seek to and we start over from 0; otherwise we resume right where we
+/// left off.
+fn resolve_start_offset(file_len: u64, stored_offset: u64) -> u64 {
+    if file_len < stored_offset {
+        0
+    } else {
+        stored_offset
+    }
+}
+
+/// Reads only the bytes appended since `offset`, parses each complete line as its own
+/// `LogEntry`, and advances `offset` past them. The file is never deleted or truncated, and a
+/// trailing partial line (the producer mid-write) is left for the next read.
+fn consume_ndjson_appended(
+    path: PathBuf,
+    action_tx: Option<mpsc::UnboundedSender<Action>>,
+    offset: Arc<Mutex<u64>>,
+) {
+    use std::io::{Read, Seek, SeekFrom};
+
+    tokio::spawn(async move {
+        let mut file = match fs::File::open(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                debug!("Error opening log file: {}", e);
+                return;
+            }
+        };
+
+        let file_len = match file.metadata() {
+            Ok(metadata) => metadata.len(),
+            Err(e) => {
+                debug!("Error reading log file metadata: {}", e);
+                return;
+            }
+        };
+
+        let start_offset = resolve_start_offset(file_len, *offset.lock().unwrap());
+        *offset.lock().unwrap() = start_offset;
+
+        if let Err(e) = file.seek(SeekFrom::Start(start_offset)) {
+            debug!("Error seeking log file: {}", e);
+            return;
+        }
+
+        let mut appended = String::new();
+        if let Err(e) = file.read_to_string(&mut appended) {
+            debug!("Error reading appended log content: {}", e);
+            return;
+        }
+
+        if appended.is_empty() {
+            return;
+        }
+
+        let Some(last_newline) = appended.rfind('\n') else {
+            // No complete line yet - still mid-write, try again next time.
+            return;
+        };
+
+        let complete_lines = &appended[..=last_newline];
+        for line in complete_lines.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<LogEntry>(line) {
+                Ok(entry) => {
+                    if let Some(tx) = &action_tx {
+                        let _ = tx.send(Action::LogFileUpdated(entry.content));
+                    }
+                }
+                Err(e) => {
+                    debug!("Error parsing NDJSON log entry: {}", e);
+                }
+            }
+        }
+
+        *offset.lock().unwrap() = start_offset + complete_lines.len() as u64;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_start_offset_resumes_from_the_stored_offset_when_the_file_has_only_grown() {
+        assert_eq!(resolve_start_offset(100, 40), 40);
+    }
+
+    #[test]
+    fn resolve_start_offset_restarts_from_zero_once_the_file_is_shorter_than_the_stored_offset() {
+        // The file was truncated or rotated out from under us - the stored offset no longer
+        // points anywhere meaningful to seek to.
+        assert_eq!(resolve_start_offset(10, 40), 0);
+    }
+
+    #[test]
+    fn resolve_start_offset_treats_an_exact_length_match_as_nothing_new_to_read() {
+        assert_eq!(resolve_start_offset(40, 40), 40);
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Where the last-used pane-to-task assignments are auto-persisted, scoped to the workspace by
+/// living under the same `tmp/nx-tui-logs` tree as run history and saved output - so a developer
+/// who always watches `build` in pane 1 and `test` in pane 2 gets that back automatically on the
+/// next `nx run-many`, without a checked-in `nx-tui.json` (see `layout_presets`). Written by
+/// `TasksList`'s own pane mutators (`assign_current_task_to_pane`, `add_pane`, `cycle_pane_task`,
+/// etc.), the only component that actually renders a pane - not by `App`.
+const PANE_SESSION_PATH: &str = "tmp/nx-tui-logs/pane-session.json";
+
+/// Which task (if any) is pinned into each output pane, and how far each pane was scrolled,
+/// persisted across TUI invocations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaneSessionState {
+    pub pane_tasks: Vec<Option<String>>,
+    /// Each pane's scroll offset into its scrollback, index-aligned with `pane_tasks`. Missing
+    /// or mismatched-length on load (e.g. an older session file from before this field existed)
+    /// just means those panes restore at the top of their scrollback.
+    #[serde(default)]
+    pub pane_scroll_offsets: Vec<usize>,
+}
+
+impl PaneSessionState {
+    /// Loads the last-persisted pane assignments, if any. Returns `None` on any I/O or parse
+    /// error so a missing/corrupt session file just means starting with no panes pinned,
+    /// rather than a startup failure.
+    pub fn load() -> Option<Self> {
+        let content = fs::read_to_string(PANE_SESSION_PATH).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Writes the current pane assignments and scroll offsets to disk, overwriting any prior
+    /// session. Silently gives up on I/O failure - this is a convenience, not data that's safe
+    /// to lose a run over.
+    pub fn save(pane_tasks: &[Option<String>], pane_scroll_offsets: &[usize]) {
+        let Some(parent) = PathBuf::from(PANE_SESSION_PATH).parent().map(PathBuf::from) else {
+            return;
+        };
+        if fs::create_dir_all(parent).is_err() {
+            return;
+        }
+        let state = PaneSessionState {
+            pane_tasks: pane_tasks.to_vec(),
+            pane_scroll_offsets: pane_scroll_offsets.to_vec(),
+        };
+        if let Ok(json) = serde_json::to_string_pretty(&state) {
+            let _ = fs::write(PANE_SESSION_PATH, json);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exercises the real `PANE_SESSION_PATH` on disk, same as the app does at runtime - there's
+    // no dependency injection for the path anywhere in this codebase.
+    #[test]
+    fn save_then_load_round_trips_pane_tasks_and_scroll_offsets() {
+        let pane_tasks = vec![Some("my-app:build".to_string()), None];
+        let pane_scroll_offsets = vec![12, 0];
+
+        PaneSessionState::save(&pane_tasks, &pane_scroll_offsets);
+        let loaded = PaneSessionState::load().expect("just-saved session should load back");
+
+        assert_eq!(loaded.pane_tasks, pane_tasks);
+        assert_eq!(loaded.pane_scroll_offsets, pane_scroll_offsets);
+
+        let _ = fs::remove_file(PANE_SESSION_PATH);
+    }
+}
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Where persisted run-history records are stored, so past runs can be compared across
+/// separate TUI invocations against the same workspace.
+const RUN_HISTORY_DIR: &str = "tmp/nx-tui-logs/history";
+
+/// How much of a task's terminal output is kept in a history record - keeping the full output
+/// would make the history store grow unboundedly across runs.
+const MAX_RECORDED_OUTPUT_BYTES: usize = 4096;
+
+/// One task's timing and outcome from a completed run, persisted so later runs can be
+/// compared against it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskHistoryRecord {
+    pub task_id: String,
+    pub started_at: u128,
+    pub completed_at: u128,
+    pub duration_ms: u128,
+    pub status: String,
+    pub cache: String,
+    pub output: String,
+}
+
+impl TaskHistoryRecord {
+    /// Builds a record from a finished task's timing/output, truncating `output` and
+    /// rejecting a record whose timings violate `completed_at >= started_at` rather than
+    /// letting a corrupt duration get persisted.
+    pub fn new(
+        task_id: String,
+        started_at: u128,
+        completed_at: u128,
+        status: String,
+        cache: String,
+        output: &str,
+    ) -> Option<Self> {
+        if completed_at < started_at {
+            return None;
+        }
+
+        let output = if output.len() > MAX_RECORDED_OUTPUT_BYTES {
+            // Back off to a char boundary so a multi-byte character straddling the limit
+            // doesn't make the slice panic.
+            let mut end = MAX_RECORDED_OUTPUT_BYTES;
+            while !output.is_char_boundary(end) {
+                end -= 1;
+            }
+            format!("{}... (truncated)", &output[..end])
+        } else {
+            output.to_string()
+        };
+
+        Some(Self {
+            task_id,
+            started_at,
+            completed_at,
+            duration_ms: completed_at - started_at,
+            status,
+            cache,
+            output,
+        })
+    }
+}
+
+/// One full TUI invocation's worth of task history records.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunHistoryEntry {
+    pub started_at: u128,
+    pub tasks: Vec<TaskHistoryRecord>,
+}
+
+/// Appends finished tasks' records to the current run and flushes the whole run to disk on
+/// every append, so a history view can read it back even if the TUI is relaunched mid-run.
+pub struct RunHistoryStore {
+    current_run: RunHistoryEntry,
+}
+
+impl RunHistoryStore {
+    pub fn new() -> Self {
+        let started_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+
+        Self {
+            current_run: RunHistoryEntry {
+                started_at,
+                tasks: Vec::new(),
+            },
+        }
+    }
+
+    /// Appends a task record to the current run and persists the whole run to disk.
+    pub fn record(&mut self, record: TaskHistoryRecord) {
+        self.current_run.tasks.push(record);
+        self.flush();
+    }
+
+    fn run_file_path(&self) -> PathBuf {
+        Path::new(RUN_HISTORY_DIR).join(format!("run-{}.json", self.current_run.started_at))
+    }
+
+    fn flush(&self) {
+        if fs::create_dir_all(RUN_HISTORY_DIR).is_err() {
+            return;
+        }
+        if let Ok(json) = serde_json::to_string_pretty(&self.current_run) {
+            let _ = fs::write(self.run_file_path(), json);
+        }
+    }
+
+    /// Loads every persisted run, most recently started first, for the history view.
+    pub fn load_all() -> Vec<RunHistoryEntry> {
+        let Ok(read_dir) = fs::read_dir(RUN_HISTORY_DIR) else {
+            return Vec::new();
+        };
+
+        let mut runs: Vec<RunHistoryEntry> = read_dir
+            .flatten()
+            .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+            .filter_map(|content| serde_json::from_str::<RunHistoryEntry>(&content).ok())
+            .collect();
+
+        runs.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+        runs
+    }
+}
+
+impl Default for RunHistoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}